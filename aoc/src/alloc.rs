@@ -0,0 +1,123 @@
+//! A global allocator counting allocations and the bytes in use, to report the memory of each part.
+//! Only installed by the `alloc-stats` feature, as every allocation then pays for a few atomics.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering::Relaxed},
+};
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting what goes through it.
+pub struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Relaxed);
+            allocated(new_size);
+        }
+        new_ptr
+    }
+}
+
+fn allocated(size: usize) {
+    ALLOCATIONS.fetch_add(1, Relaxed);
+    let current = CURRENT.fetch_add(size, Relaxed) + size;
+    PEAK.fetch_max(current, Relaxed);
+}
+
+/// The memory used while running something, on top of what was already allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub peak_bytes: usize,
+    /// Allocations and reallocations
+    pub allocations: usize,
+}
+
+/// Runs `f` and measures its memory. Everything is zero when [`Counting`] is not the global
+/// allocator, and other threads allocating at the same time are counted too.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let base = CURRENT.load(Relaxed);
+    PEAK.store(base, Relaxed);
+    let allocations = ALLOCATIONS.load(Relaxed);
+    let result = f();
+    let usage = Usage {
+        peak_bytes: PEAK.load(Relaxed).saturating_sub(base),
+        allocations: ALLOCATIONS.load(Relaxed) - allocations,
+    };
+    (result, usage)
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut size = self.peak_bytes as f64;
+        let mut unit = 0;
+        const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+        while size >= 1024.0 && unit < UNITS.len() - 1 {
+            size /= 1024.0;
+            unit += 1;
+        }
+        write!(
+            f,
+            "peak {size:.1} {}, {} allocations",
+            UNITS[unit], self.allocations
+        )
+    }
+}
+
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measures_the_peak_and_the_allocations() {
+        let (sum, usage) = measure(|| {
+            let big = vec![1u8; 1 << 20];
+            let small: Vec<Vec<u8>> = (0..100).map(|_| vec![1]).collect();
+            big.len() + small.len()
+        });
+        assert_eq!(sum, (1 << 20) + 100);
+        assert!(usage.peak_bytes >= (1 << 20) + 100, "{usage:?}");
+        assert!(usage.allocations >= 102, "{usage:?}");
+    }
+
+    #[test]
+    fn prints_sizes_in_binary_units() {
+        let usage = |peak_bytes| Usage {
+            peak_bytes,
+            allocations: 3,
+        };
+        assert_eq!(usage(512).to_string(), "peak 512.0 B, 3 allocations");
+        assert_eq!(usage(3 << 19).to_string(), "peak 1.5 MiB, 3 allocations");
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{default_input, input_path, workspace_dir};
+use common::{OrExpected, ParseError, Result};
+use std::{collections::HashMap, fs, io, path::PathBuf};
+
+/// The known-good answer of one part of a day for one of its input files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub day: u8,
+    pub part: u8,
+    /// File name inside the `day<N>` directory
+    pub input: String,
+    pub expected: String,
+    /// Parameter file inside the `day<N>` directory, for inputs smaller than the real puzzle
+    pub params: Option<String>,
+}
+
+pub fn registry_path() -> PathBuf {
+    workspace_dir().join("answers.txt")
+}
+
+pub fn load() -> io::Result<Vec<Answer>> {
+    let path = registry_path();
+    let registry = fs::read_to_string(&path)?;
+    Ok(parse(&registry).map_err(|err| err.with_file(path))?)
+}
+
+/// The registered answer of each day and part on the real input of `year`.
+pub fn expected(year: u16) -> io::Result<HashMap<(u8, u8), String>> {
+    Ok(load()?
+        .into_iter()
+        .filter(|answer| input_path(answer.day, &answer.input) == default_input(year, answer.day))
+        .map(|answer| ((answer.day, answer.part), answer.expected))
+        .collect())
+}
+
+/// Reads one `<day> <part> <input> <answer> [params]` entry per line, skipping blank lines and `#`
+/// comments.
+pub fn parse(registry: &str) -> Result<Vec<Answer>> {
+    registry
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|line| {
+            let mut fields = line.split_whitespace();
+            let mut next_field = |expected: &str| {
+                fields
+                    .next()
+                    .or_expected(registry, &line[line.len()..], expected)
+            };
+            let day = next_field("a day")?;
+            let part = next_field("a part")?;
+            let input = next_field("an input file")?;
+            let expected = next_field("an answer")?;
+            let params = fields.next();
+            if let Some(extra) = fields.next() {
+                return Err(ParseError::at(registry, extra, "the end of the entry"));
+            }
+            Ok(Answer {
+                day: day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .or_expected(registry, day, "a day between 1 and 25")?,
+                part: part
+                    .parse()
+                    .ok()
+                    .filter(|part| (1..=2).contains(part))
+                    .or_expected(registry, part, "part 1 or 2")?,
+                input: input.to_string(),
+                expected: expected.to_string(),
+                params: params.map(str::to_string),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_entries_and_skips_comments() {
+        let registry = "# day part input answer\n\n17 1 example_1.txt 4,6,3\n18 1 example.txt 22 example.toml\n";
+        assert_eq!(
+            parse(registry),
+            Ok(vec![
+                Answer {
+                    day: 17,
+                    part: 1,
+                    input: "example_1.txt".to_string(),
+                    expected: "4,6,3".to_string(),
+                    params: None,
+                },
+                Answer {
+                    day: 18,
+                    part: 1,
+                    input: "example.txt".to_string(),
+                    expected: "22".to_string(),
+                    params: Some("example.toml".to_string()),
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn points_at_invalid_entries() {
+        assert_eq!(
+            parse("1 3 example.txt 11\n"),
+            Err(ParseError::new(1, 3, "part 1 or 2", "`3`"))
+        );
+        assert_eq!(
+            parse("1 1 example.txt\n"),
+            Err(ParseError::new(1, 16, "an answer", "end of line"))
+        );
+    }
+}
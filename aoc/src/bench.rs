@@ -0,0 +1,89 @@
+use crate::{
+    days::{SolveError, Solver},
+    params::Params,
+};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Wall time of one part over several runs, in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub max_ns: u64,
+}
+
+impl Timing {
+    pub fn min(&self) -> Duration {
+        Duration::from_nanos(self.min_ns)
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_nanos(self.max_ns)
+    }
+}
+
+/// Runs `solver` `runs` times on the same input. Stops at the first error.
+pub fn measure(
+    day: u8,
+    part: u8,
+    solver: Solver,
+    input_name: &str,
+    input: &str,
+    params: &Params,
+    runs: usize,
+) -> Result<Timing, SolveError> {
+    let mut answer = String::new();
+    let mut times = Vec::with_capacity(runs);
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        answer = solver(input, params)?;
+        times.push(start.elapsed());
+    }
+    times.sort();
+    Ok(Timing {
+        day,
+        part,
+        input: input_name.to_string(),
+        answer,
+        runs: times.len(),
+        min_ns: nanos(times[0]),
+        median_ns: nanos(median(&times)),
+        max_ns: nanos(times[times.len() - 1]),
+    })
+}
+
+/// The middle of an already sorted, non-empty list of times.
+fn median(times: &[Duration]) -> Duration {
+    let middle = times.len() / 2;
+    if times.len().is_multiple_of(2) {
+        (times[middle - 1] + times[middle]) / 2
+    } else {
+        times[middle]
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn median_of_odd_and_even_runs() {
+        let ms = Duration::from_millis;
+        assert_eq!(median(&[ms(1), ms(2), ms(10)]), ms(2));
+        assert_eq!(median(&[ms(1), ms(2), ms(4), ms(10)]), ms(3));
+    }
+}
@@ -0,0 +1,56 @@
+//! Requests to the Advent of Code website, or to anything answering like it.
+
+use std::io::{Error, Result};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    /// `session` is the value of the `session` cookie of a logged in browser.
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    /// The puzzle input of `day`, exactly as served.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        self.agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call()
+            .map_err(|err| http_error(&url, err))?
+            .into_string()
+    }
+
+    /// Sends `answer` for `part` of `day` and returns the page answering it.
+    pub fn answer(&self, year: u16, day: u8, part: u8, answer: &str) -> Result<String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        self.agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|err| http_error(&url, err))?
+            .into_string()
+    }
+}
+
+fn http_error(url: &str, err: ureq::Error) -> Error {
+    match err {
+        ureq::Error::Status(status, response) => {
+            Error::other(format!("{url}: {status} {}", response.status_text()))
+        }
+        // Transport errors already name the URL
+        ureq::Error::Transport(transport) => Error::other(transport.to_string()),
+    }
+}
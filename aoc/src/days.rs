@@ -0,0 +1,316 @@
+use crate::params::Params;
+use common::{Answer, ParseError, Puzzle};
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+/// Runs one part of a day against the puzzle input, with the puzzle constants of `Params`.
+pub type Solver = fn(&str, &Params) -> Result<String, SolveError>;
+
+/// Every problem of an input, see [`Puzzle::check`], with the puzzle constants of `Params`.
+pub type Checker = fn(&str, &Params) -> Vec<ParseError>;
+
+pub struct Day {
+    pub number: u8,
+    pub parts: &'static [Solver],
+    pub check: Checker,
+}
+
+impl Day {
+    pub fn part(&self, part: u8) -> Option<Solver> {
+        self.parts.get(usize::from(part).checked_sub(1)?).copied()
+    }
+}
+
+/// The solutions of one year.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [Day],
+}
+
+/// Every year with solutions. `aoc new` registers new years above the marker.
+pub static YEARS: &[Year] = &[
+    Year {
+        year: 2024,
+        days: &DAYS,
+    },
+    // aoc new: next year
+];
+
+/// A day of this repository's main year, [`crate::YEAR`].
+pub fn get(day: u8) -> Option<&'static Day> {
+    get_in(crate::YEAR, day)
+}
+
+pub fn get_in(year: u16, day: u8) -> Option<&'static Day> {
+    YEARS
+        .iter()
+        .find(|y| y.year == year)?
+        .days
+        .iter()
+        .find(|d| d.number == day)
+}
+
+/// Why a part gave no answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Parse(ParseError),
+    /// The input parses but breaks a guarantee of the puzzle, e.g. a maze whose exit is cut off.
+    /// Nothing in the input is wrong on its own, so there is no line to point at.
+    NoAnswer {
+        file: Option<PathBuf>,
+    },
+}
+
+impl SolveError {
+    pub fn with_file(self, file: impl Into<PathBuf>) -> Self {
+        match self {
+            SolveError::Parse(err) => SolveError::Parse(err.with_file(file)),
+            SolveError::NoAnswer { .. } => SolveError::NoAnswer {
+                file: Some(file.into()),
+            },
+        }
+    }
+}
+
+impl From<ParseError> for SolveError {
+    fn from(err: ParseError) -> Self {
+        SolveError::Parse(err)
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(err) => err.fmt(f),
+            SolveError::NoAnswer { file } => {
+                if let Some(file) = file {
+                    write!(f, "{}: ", file.display())?;
+                }
+                f.write_str("the part has no answer for this input")
+            }
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<SolveError> for io::Error {
+    fn from(err: SolveError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Parses the input and solves the first part of puzzle `P`.
+fn part1<P: Puzzle>(input: &str, _: &Params) -> Result<String, SolveError> {
+    answer(P::part1(&P::parse(input)?))
+}
+
+/// Parses the input and solves the second part of puzzle `P`.
+fn part2<P: Puzzle>(input: &str, _: &Params) -> Result<String, SolveError> {
+    answer(P::part2(&P::parse(input)?))
+}
+
+/// Every problem of an input of puzzle `P`.
+fn check<P: Puzzle>(input: &str, _: &Params) -> Vec<ParseError> {
+    P::check(input)
+}
+
+/// A part without an answer means that the input breaks a guarantee of the puzzle.
+fn answer(answer: impl Answer) -> Result<String, SolveError> {
+    answer.answer().ok_or(SolveError::NoAnswer { file: None })
+}
+
+pub static DAYS: [Day; 25] = [
+    Day {
+        number: 1,
+        parts: &[part1::<day1::Day1>, part2::<day1::Day1>],
+        check: check::<day1::Day1>,
+    },
+    Day {
+        number: 2,
+        parts: &[part1::<day2::Day2>, part2::<day2::Day2>],
+        check: check::<day2::Day2>,
+    },
+    Day {
+        number: 3,
+        parts: &[part1::<day3::Day3>, part2::<day3::Day3>],
+        check: check::<day3::Day3>,
+    },
+    Day {
+        number: 4,
+        parts: &[part1::<day4::Day4>, part2::<day4::Day4>],
+        check: check::<day4::Day4>,
+    },
+    Day {
+        number: 5,
+        parts: &[part1::<day5::Day5>, part2::<day5::Day5>],
+        check: check::<day5::Day5>,
+    },
+    Day {
+        number: 6,
+        parts: &[part1::<day6::Day6>, part2::<day6::Day6>],
+        check: check::<day6::Day6>,
+    },
+    Day {
+        number: 7,
+        parts: &[part1::<day7::Day7>, part2::<day7::Day7>],
+        check: check::<day7::Day7>,
+    },
+    Day {
+        number: 8,
+        parts: &[part1::<day8::Day8>, part2::<day8::Day8>],
+        check: check::<day8::Day8>,
+    },
+    Day {
+        number: 9,
+        parts: &[part1::<day9::Day9>, part2::<day9::Day9>],
+        check: check::<day9::Day9>,
+    },
+    Day {
+        number: 10,
+        parts: &[part1::<day10::Day10>, part2::<day10::Day10>],
+        check: check::<day10::Day10>,
+    },
+    Day {
+        number: 11,
+        parts: &[
+            |input, params| {
+                let stones = day11::Day11::parse(input)?;
+                answer(day11::solve(&stones, params.day11.part1_blinks))
+            },
+            |input, params| {
+                let stones = day11::Day11::parse(input)?;
+                answer(day11::solve_part_two(&stones, params.day11.part2_blinks))
+            },
+        ],
+        check: check::<day11::Day11>,
+    },
+    Day {
+        number: 12,
+        parts: &[part1::<day12::Day12>, part2::<day12::Day12>],
+        check: check::<day12::Day12>,
+    },
+    Day {
+        number: 13,
+        parts: &[part1::<day13::Day13>, part2::<day13::Day13>],
+        check: check::<day13::Day13>,
+    },
+    Day {
+        number: 14,
+        parts: &[
+            |input, params| {
+                let day14 = &params.day14;
+                let map_size = (day14.width, day14.height);
+                let robots = day14::read_input(input, map_size)?;
+                Ok(day14::safety_factor(&robots, map_size, day14.seconds).to_string())
+            },
+            |input, params| {
+                let map_size = (params.day14.width, params.day14.height);
+                let robots = day14::read_input(input, map_size)?;
+                Ok(day14::find_christmas_tree(&robots, map_size).to_string())
+            },
+        ],
+        check: |input, params| day14::check_input(input, (params.day14.width, params.day14.height)),
+    },
+    Day {
+        number: 15,
+        parts: &[part1::<day15::Day15>, part2::<day15::Day15>],
+        check: check::<day15::Day15>,
+    },
+    Day {
+        number: 16,
+        parts: &[part1::<day16::Day16>, part2::<day16::Day16>],
+        check: check::<day16::Day16>,
+    },
+    Day {
+        number: 17,
+        parts: &[part1::<day17::Day17>, part2::<day17::Day17>],
+        check: check::<day17::Day17>,
+    },
+    Day {
+        number: 18,
+        parts: &[
+            |input, params| {
+                let day18 = &params.day18;
+                let bytes = day18::read_input(input, day18.grid_size)?;
+                let steps = day18::shortest_path_after(&bytes, day18.grid_size, day18.bytes);
+                answer(steps)
+            },
+            |input, params| {
+                let bytes = day18::read_input(input, params.day18.grid_size)?;
+                answer(day18::first_blocking_byte(&bytes, params.day18.grid_size))
+            },
+        ],
+        check: |input, params| day18::check_input(input, params.day18.grid_size),
+    },
+    Day {
+        number: 19,
+        parts: &[part1::<day19::Day19>, part2::<day19::Day19>],
+        check: check::<day19::Day19>,
+    },
+    Day {
+        number: 20,
+        parts: &[
+            |input, params| {
+                let racetrack = day20::Day20::parse(input)?;
+                let limit = params.day20.part1_min_saving;
+                Ok(day20::count_short_cheats(&racetrack, limit).to_string())
+            },
+            |input, params| {
+                let racetrack = day20::Day20::parse(input)?;
+                let limit = params.day20.part2_min_saving;
+                Ok(day20::count_long_cheats(&racetrack, limit).to_string())
+            },
+        ],
+        check: check::<day20::Day20>,
+    },
+    Day {
+        number: 21,
+        parts: &[
+            |input, params| {
+                let codes = day21::Day21::parse(input)?;
+                Ok(day21::solution(&codes, params.day21.part1_robots).to_string())
+            },
+            |input, params| {
+                let codes = day21::Day21::parse(input)?;
+                Ok(day21::solution(&codes, params.day21.part2_robots).to_string())
+            },
+        ],
+        check: check::<day21::Day21>,
+    },
+    Day {
+        number: 22,
+        parts: &[
+            |input, params| {
+                let secret_numbers = day22::Day22::parse(input)?;
+                let iterations = params.day22.iterations;
+                Ok(day22::sum_of_secret_numbers(&secret_numbers, iterations).to_string())
+            },
+            |input, params| {
+                let secret_numbers = day22::Day22::parse(input)?;
+                let iterations = params.day22.iterations;
+                Ok(day22::most_bananas(&secret_numbers, iterations).to_string())
+            },
+        ],
+        check: check::<day22::Day22>,
+    },
+    Day {
+        number: 23,
+        parts: &[part1::<day23::Day23>, part2::<day23::Day23>],
+        check: check::<day23::Day23>,
+    },
+    Day {
+        number: 24,
+        parts: &[part1::<day24::Day24>, part2::<day24::Day24>],
+        check: check::<day24::Day24>,
+    },
+    Day {
+        number: 25,
+        parts: &[part1::<day25::Day25>],
+        check: check::<day25::Day25>,
+    },
+];
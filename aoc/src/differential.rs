@@ -0,0 +1,265 @@
+//! `aoc differential`: the days that keep a straightforward and an optimised algorithm side by
+//! side, compared on generated inputs.
+
+use crate::{params::Params, run_all::isolated};
+use common::Puzzle;
+use std::{collections::HashMap, ops::Range};
+
+/// Two algorithms of one day that must give the same answer.
+pub struct Pair {
+    pub day: u8,
+    /// What is compared, e.g. "simulation vs memoisation"
+    pub name: &'static str,
+    /// Splits a line into the pieces that minimising may drop, for inputs with long lines
+    pub separator: Option<&'static str>,
+    /// The answers of both algorithms, each `None` when the input has no answer, or `None` when
+    /// the input is not one both are meant to solve
+    pub compare: fn(&str, &Params) -> Option<[Option<String>; 2]>,
+}
+
+pub static PAIRS: [Pair; 3] = [
+    Pair {
+        day: 11,
+        name: "simulation vs memoisation",
+        separator: Some(" "),
+        compare: day11,
+    },
+    Pair {
+        day: 18,
+        name: "brute force vs binary search",
+        separator: None,
+        compare: day18,
+    },
+    Pair {
+        day: 19,
+        name: "possible designs vs designs with arrangements",
+        separator: Some(", "),
+        compare: day19,
+    },
+];
+
+fn day11(input: &str, params: &Params) -> Option<[Option<String>; 2]> {
+    let stones = day11::Day11::parse(input).ok()?;
+    let blinks = params.day11.part1_blinks;
+    Some([
+        day11::solve(&stones, blinks).map(|count| count.to_string()),
+        day11::solve_part_two(&stones, blinks).map(|count| count.to_string()),
+    ])
+}
+
+fn day18(input: &str, params: &Params) -> Option<[Option<String>; 2]> {
+    let grid_size = params.day18.grid_size;
+    let bytes = day18::read_input(input, grid_size).ok()?;
+    Some([
+        day18::first_blocking_byte_brute_force(&bytes, grid_size),
+        day18::first_blocking_byte(&bytes, grid_size),
+    ])
+}
+
+fn day19(input: &str, _: &Params) -> Option<[Option<String>; 2]> {
+    let (towels, designs) = day19::Day19::parse(input).ok()?;
+    let possible = designs
+        .iter()
+        .filter(|design| day19::is_possible(design, towels.clone()))
+        .count();
+    let mut memoization = HashMap::new();
+    let with_arrangements = designs
+        .iter()
+        .filter(|design| day19::n_possibilities(design, towels.clone(), &mut memoization) > 0)
+        .count();
+    Some([
+        Some(possible.to_string()),
+        Some(with_arrangements.to_string()),
+    ])
+}
+
+pub fn get(day: u8) -> Option<&'static Pair> {
+    PAIRS.iter().find(|pair| pair.day == day)
+}
+
+#[derive(Debug)]
+pub struct Disagreement {
+    pub seed: u64,
+    /// The smallest input found on which the algorithms still disagree
+    pub input: String,
+    /// Both answers, or the panic of one algorithm
+    pub reason: String,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+    /// Both algorithms gave the same answer on every input, and had one for some of them
+    Agree,
+    /// Neither algorithm had an answer for any input, so nothing was compared
+    NoAnswers,
+    Disagree(Disagreement),
+}
+
+/// What both algorithms make of one input
+enum Comparison {
+    Unanswered,
+    Same,
+    Different(String),
+}
+
+/// Compares `pair` on the input of `generators` for every seed of `seeds`, with `params` fitted to
+/// the inputs of `size`. Stops at the first disagreement, returned with its input minimised.
+pub fn check(pair: &Pair, seeds: Range<u64>, size: usize, params: &Params) -> Outcome {
+    let params = &params.generated(size);
+    let mut answered = false;
+    for seed in seeds {
+        let input = generators::generate(pair.day, seed, size).expect("Every day has a generator");
+        match compare(pair, &input, params) {
+            Comparison::Unanswered => {}
+            Comparison::Same => answered = true,
+            Comparison::Different(_) => {
+                let input = minimise(&input, pair.separator, |input| {
+                    disagreement(pair, input, params).is_some()
+                });
+                let reason =
+                    disagreement(pair, &input, params).expect("Minimising keeps the failure");
+                return Outcome::Disagree(Disagreement {
+                    seed,
+                    input,
+                    reason,
+                });
+            }
+        }
+    }
+    match answered {
+        true => Outcome::Agree,
+        false => Outcome::NoAnswers,
+    }
+}
+
+fn compare(pair: &Pair, input: &str, params: &Params) -> Comparison {
+    // The panics are part of the disagreement, not printed as they happen
+    match isolated(|| (pair.compare)(input, params)) {
+        Ok(Some([first, second])) if first != second => {
+            Comparison::Different(format!("{} vs {}", describe(first), describe(second)))
+        }
+        Ok(Some([Some(_), _])) => Comparison::Same,
+        Ok(_) => Comparison::Unanswered,
+        Err(message) => Comparison::Different(format!("panicked: {message}")),
+    }
+}
+
+fn disagreement(pair: &Pair, input: &str, params: &Params) -> Option<String> {
+    match compare(pair, input, params) {
+        Comparison::Different(reason) => Some(reason),
+        Comparison::Unanswered | Comparison::Same => None,
+    }
+}
+
+fn describe(answer: Option<String>) -> String {
+    answer.unwrap_or_else(|| "no answer".to_string())
+}
+
+/// Drops lines of `input`, then pieces of the lines left split by `separator`, as long as `fails`
+/// holds on what remains.
+pub fn minimise(input: &str, separator: Option<&str>, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines = reduce(input.lines().map(str::to_string).collect(), |lines| {
+        fails(&join(lines))
+    });
+    if let Some(separator) = separator {
+        for i in 0..lines.len() {
+            let pieces = lines[i].split(separator).map(str::to_string).collect();
+            let pieces = reduce(pieces, |pieces| {
+                let mut candidate = lines.clone();
+                candidate[i] = pieces.join(separator);
+                fails(&join(&candidate))
+            });
+            lines[i] = pieces.join(separator);
+        }
+    }
+    join(&lines)
+}
+
+fn join(lines: &[String]) -> String {
+    lines.iter().map(|line| line.clone() + "\n").collect()
+}
+
+/// Delta debugging: removes chunks of `items`, halving their size whenever none can go, while
+/// `fails` holds on the items left.
+fn reduce<T: Clone>(mut items: Vec<T>, fails: impl Fn(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+    while items.len() > 1 {
+        let chunk_size = items.len().div_ceil(chunks);
+        let reduced = (0..items.len()).step_by(chunk_size).find_map(|start| {
+            let mut rest = items[..start].to_vec();
+            rest.extend_from_slice(&items[(start + chunk_size).min(items.len())..]);
+            fails(&rest).then_some(rest)
+        });
+        match reduced {
+            Some(rest) => {
+                items = rest;
+                chunks = (chunks - 1).max(2);
+            }
+            None if chunks >= items.len() => break,
+            None => chunks = (chunks * 2).min(items.len()),
+        }
+    }
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimises_lines_then_pieces() {
+        let input = "1 2\n3 4 5\n6\n7 8 9\n";
+        let fails = |input: &str| input.contains('4') && input.contains('8');
+        assert_eq!(minimise(input, Some(" "), fails), "4\n8\n");
+        assert_eq!(minimise(input, None, fails), "3 4 5\n7 8 9\n");
+    }
+
+    #[test]
+    fn panics_are_disagreements() {
+        let pair = Pair {
+            day: 11,
+            name: "panics on zero",
+            separator: Some(" "),
+            compare: |input, _| {
+                assert!(!input.split_whitespace().any(|stone| stone == "0"), "zero");
+                Some([Some("1".to_string()), Some("1".to_string())])
+            },
+        };
+        let Outcome::Disagree(disagreement) = check(&pair, 0..20, 8, &Params::default()) else {
+            panic!("The stones of some seed have a zero");
+        };
+        assert_eq!(disagreement.input, "0\n");
+        assert_eq!(disagreement.reason, "panicked: zero");
+    }
+
+    #[test]
+    fn inputs_without_answers_compare_nothing() {
+        let pair = Pair {
+            day: 18,
+            name: "never answers",
+            separator: None,
+            compare: |_, _| Some([None, None]),
+        };
+        assert!(matches!(
+            check(&pair, 0..3, 10, &Params::default()),
+            Outcome::NoAnswers
+        ));
+        assert!(matches!(
+            check(get(18).unwrap(), 0..3, 10, &Params::default()),
+            Outcome::Agree
+        ));
+    }
+
+    #[test]
+    fn an_exit_left_reachable_is_compared_too() {
+        let mut params = Params::default();
+        params.day18.grid_size = 2;
+        let compare = get(18).unwrap().compare;
+        assert_eq!(compare("", &params), Some([None, None]));
+        assert_eq!(compare("1,1\n0,2\n", &params), Some([None, None]));
+        assert_eq!(
+            compare("1,1\n0,1\n2,1\n", &params),
+            Some([Some("2,1".to_string()), Some("2,1".to_string())])
+        );
+    }
+}
@@ -0,0 +1,32 @@
+use crate::client::Client;
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+};
+
+/// Where the input of `day` of `year` is cached inside `cache_dir`.
+pub fn cached_input(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Downloads the input of `day` into the cache and returns its path. Inputs never change, so an
+/// input already in the cache is an error rather than a request the website does not need.
+pub fn fetch(client: &Client, cache_dir: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    let path = cached_input(cache_dir, year, day);
+    if path.exists() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} is already cached", path.display()),
+        ));
+    }
+    let input = client.input(year, day)?;
+    let directory = path.parent().expect("Cached inputs are inside a directory");
+    fs::create_dir_all(directory)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", directory.display())))?;
+    fs::write(&path, input)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", path.display())))?;
+    Ok(path)
+}
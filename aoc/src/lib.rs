@@ -0,0 +1,71 @@
+use std::path::{Path, PathBuf};
+
+pub mod alloc;
+pub mod answers;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod differential;
+pub mod fetch;
+pub mod logger;
+pub mod maps;
+pub mod params;
+pub mod report;
+pub mod run_all;
+pub mod runs;
+pub mod scaffold;
+pub mod submit;
+pub mod tui;
+pub mod watch;
+
+/// The year of the puzzles solved here.
+pub const YEAR: u16 = 2024;
+
+/// The root of the workspace, so inputs are found from any working directory.
+pub fn workspace_dir() -> &'static Path {
+    Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/.."))
+}
+
+/// The default cache of `aoc fetch`.
+pub fn inputs_dir() -> PathBuf {
+    workspace_dir().join("inputs")
+}
+
+/// The crate of a day inside the workspace: `day<N>` for [`YEAR`], and `<year>/day<N>` for the
+/// other years.
+pub fn crate_dir(year: u16, day: u8) -> PathBuf {
+    if year == YEAR {
+        PathBuf::from(format!("day{day}"))
+    } else {
+        Path::new(&year.to_string()).join(format!("day{day}"))
+    }
+}
+
+/// The name of the crate of a day, unique across years.
+pub fn crate_name(year: u16, day: u8) -> String {
+    if year == YEAR {
+        format!("day{day}")
+    } else {
+        format!("day{day}_{year}")
+    }
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    workspace_dir().join(crate_dir(year, day))
+}
+
+/// `input.txt` of the day, or the input fetched for the day when that file is missing.
+pub fn default_input(year: u16, day: u8) -> PathBuf {
+    let path = day_dir(year, day).join("input.txt");
+    let fetched = fetch::cached_input(&inputs_dir(), year, day);
+    if !path.exists() && fetched.exists() {
+        fetched
+    } else {
+        path
+    }
+}
+
+/// Resolves `file` inside the `day<N>` directory.
+pub fn input_path(day: u8, file: &str) -> PathBuf {
+    day_dir(YEAR, day).join(file)
+}
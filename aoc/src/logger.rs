@@ -0,0 +1,61 @@
+//! Prints the diagnostics of the solvers to stderr, as many of them as the `-v` flags ask for, or
+//! keeps them for the terminal UI while it captures them.
+
+use log::{LevelFilter, Log, Metadata, Record};
+use std::{mem, sync::Mutex};
+
+struct Stderr;
+
+/// The records kept since the last [`take`], while capturing.
+static CAPTURED: Mutex<Option<Vec<String>>> = Mutex::new(None);
+
+impl Log for Stderr {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            let line = format!("[{} {}] {}", record.level(), record.target(), record.args());
+            match CAPTURED.lock().unwrap().as_mut() {
+                Some(captured) => captured.push(line),
+                None => eprintln!("{line}"),
+            }
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Only warnings without `-v`, then info with `-v`, debug with `-vv` and trace from `-vvv` on.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => LevelFilter::Warn,
+        1 => LevelFilter::Info,
+        2 => LevelFilter::Debug,
+        _ => LevelFilter::Trace,
+    };
+    log::set_max_level(level);
+    // Only fails when a logger is already set, which then keeps working
+    let _ = log::set_logger(&Stderr);
+}
+
+/// Keeps the records from now on instead of printing them, e.g. while a full screen UI is shown.
+pub fn capture() {
+    *CAPTURED.lock().unwrap() = Some(Vec::new());
+}
+
+/// Prints the records again.
+pub fn release() {
+    *CAPTURED.lock().unwrap() = None;
+}
+
+/// The records captured since the last call.
+pub fn take() -> Vec<String> {
+    CAPTURED
+        .lock()
+        .unwrap()
+        .as_mut()
+        .map(mem::take)
+        .unwrap_or_default()
+}
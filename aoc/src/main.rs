@@ -0,0 +1,615 @@
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use serde::Serialize;
+use std::{
+    ffi::OsString,
+    fs,
+    io::{self, Error, ErrorKind, Read, Result},
+    ops::Range,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::Instant,
+};
+
+use aoc::{
+    alloc, bench, client, days, differential, fetch, logger, params::Params, report, run_all, runs,
+    scaffold, submit, tui, watch,
+};
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: alloc::Counting = alloc::Counting;
+
+#[derive(Debug, Parser)]
+#[command(about = "Advent of Code 2024 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Print what the solvers log to stderr: `-v` for info, `-vv` for debug
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// TOML file of puzzle parameters. Defaults to `aoc.toml` in the workspace, when present
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Override one puzzle parameter, e.g. `--set day14.seconds=50`
+    #[arg(long = "set", value_name = "KEY=VALUE", global = true)]
+    overrides: Vec<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run one or all parts of a day
+    Run {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25), required_unless_present = "all")]
+        day: Option<u8>,
+        /// Part to run. Runs every part of the day when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to `input.txt` of the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// How answers are printed
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+        /// Run every registered example and every real input instead, and print a summary table
+        #[arg(long, conflicts_with_all = ["day", "part", "input", "format", "year"])]
+        all: bool,
+        /// Worker threads of `--all`. Defaults to the number of CPUs
+        #[arg(long, requires = "all", value_parser = clap::value_parser!(u16).range(1..))]
+        jobs: Option<u16>,
+        /// The runs of `--all` on the real inputs are kept in `<cache-dir>/<year>/runs.txt`
+        #[arg(long, requires = "all", default_value_os_t = aoc::inputs_dir())]
+        cache_dir: PathBuf,
+    },
+    /// Time parts several times and report their min, median and max wall time
+    Bench {
+        /// Day to benchmark. Benchmarks every day with an `input.txt` when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// Part to benchmark. Benchmarks every part when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to `input.txt` of the day
+        #[arg(long, requires = "day")]
+        input: Option<PathBuf>,
+        /// How many times each part runs
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u16).range(1..))]
+        runs: u16,
+        /// Also write the timings as JSON to this file
+        #[arg(long)]
+        json: Option<PathBuf>,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+    },
+    /// Report every problem of a puzzle input, without solving it
+    Check {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to `input.txt` of the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+    },
+    /// Print a random puzzle input for a day
+    Generate {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// How big the input is. What it counts depends on the day, see `generators`
+        #[arg(long)]
+        size: Option<usize>,
+        /// Seed of the input. A random one is used, and printed to stderr, when omitted
+        #[arg(long)]
+        seed: Option<u64>,
+    },
+    /// Compare the two algorithms of the days that have both on generated inputs, printing the
+    /// minimised input of any disagreement
+    Differential {
+        /// Day to check. Checks 11, 18 and 19 when omitted
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: Option<u8>,
+        /// How many inputs to generate for each day
+        #[arg(long, default_value_t = 100)]
+        cases: u64,
+        /// Seed of the first input, the next ones follow it
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// How big the inputs are. Defaults to the size of the real puzzle input
+        #[arg(long)]
+        size: Option<usize>,
+    },
+    /// Download the puzzle input of a day into the local cache
+    Fetch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+        /// Inputs are cached as `<cache-dir>/<year>/day<N>.txt`
+        #[arg(long, default_value_os_t = aoc::inputs_dir())]
+        cache_dir: PathBuf,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Solve a part and send its answer, unless earlier verdicts already rule it out
+    Submit {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Puzzle input file, or `-` to read it from stdin. Defaults to `input.txt` of the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+        /// Verdicts are kept in `<cache-dir>/<year>/submissions.txt`
+        #[arg(long, default_value_os_t = aoc::inputs_dir())]
+        cache_dir: PathBuf,
+        #[command(flatten)]
+        remote: Remote,
+    },
+    /// Rebuild and rerun a day whenever its crate or its input changes, showing how answers changed
+    Watch {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Puzzle input file. Defaults to `input.txt` of the day
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+    },
+    /// Browse the days in a terminal dashboard, running parts and drawing the maps with a keypress
+    Tui {
+        #[arg(long, default_value_t = aoc::YEAR)]
+        year: u16,
+        /// The last run of each part is kept in `<cache-dir>/<year>/runs.txt`
+        #[arg(long, default_value_os_t = aoc::inputs_dir())]
+        cache_dir: PathBuf,
+    },
+    /// Write one HTML page with the answers and times of the runs kept by `run --all` and `tui`,
+    /// and the maps of days 12, 14 and 16
+    Report {
+        /// Where the runs are kept, see `run --all`
+        #[arg(long, default_value_os_t = aoc::inputs_dir())]
+        cache_dir: PathBuf,
+        #[arg(long, default_value = "report.html")]
+        output: PathBuf,
+    },
+    /// Create the crate of a new day, with a placeholder example and test, and register it
+    New {
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// Year of the puzzle. Its days live in `<year>/day<N>`
+        #[arg(long)]
+        year: u16,
+    },
+}
+
+/// How to reach the website.
+#[derive(Debug, Args)]
+struct Remote {
+    /// Value of the `session` cookie of a logged in browser
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: String,
+    /// Where the website lives, e.g. a local stub
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Remote {
+    fn client(&self) -> client::Client {
+        client::Client::new(&self.base_url, &self.session)
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    /// `Day N part P: answer` lines
+    Text,
+    /// One JSON record per line, and nothing else on stdout
+    Json,
+}
+
+/// What `--format json` prints for each part.
+#[derive(Debug, Serialize)]
+struct Record<'a> {
+    day: u8,
+    part: u8,
+    input: &'a Path,
+    answer: String,
+    /// Wall time of the solver, in seconds.
+    elapsed: f64,
+    /// With the `alloc-stats` feature, the most bytes the solver had allocated at once.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    peak_bytes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allocations: Option<usize>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    logger::init(cli.verbose);
+    let config = cli.config.or_else(|| {
+        let default = aoc::workspace_dir().join("aoc.toml");
+        default.exists().then_some(default)
+    });
+    let params = match Params::load(config.as_deref(), &cli.overrides) {
+        Ok(params) => params,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            format,
+            year,
+            all: false,
+            ..
+        } => run(
+            year,
+            day.expect("clap requires a day"),
+            part,
+            input,
+            format,
+            &params,
+        ),
+        Command::Run {
+            jobs, cache_dir, ..
+        } => run_all(jobs, &cache_dir, &params),
+        Command::Bench {
+            day,
+            part,
+            input,
+            runs,
+            json,
+            year,
+        } => bench(year, day, part, input, runs.into(), json, &params),
+        Command::Check { day, input, year } => check(year, day, input, &params),
+        Command::Generate { day, size, seed } => generate(day, size, seed),
+        Command::Differential {
+            day,
+            cases,
+            seed,
+            size,
+        } => differential(day, seed..seed + cases, size, &params),
+        Command::Fetch {
+            day,
+            year,
+            cache_dir,
+            remote,
+        } => fetch::fetch(&remote.client(), &cache_dir, year, day).map(|path| {
+            println!("Day {day} input saved to {}", path.display());
+        }),
+        Command::Submit {
+            day,
+            part,
+            input,
+            year,
+            cache_dir,
+            remote,
+        } => submit(day, part, input, year, &cache_dir, &remote, &params),
+        Command::Watch { day, input, year } => {
+            // The runs rebuilt by `watch` get the same parameters
+            config
+                .as_deref()
+                .map(watch::absolute)
+                .transpose()
+                .and_then(|config| {
+                    let mut args: Vec<OsString> = Vec::new();
+                    if let Some(config) = config {
+                        args.extend(["--config".into(), config.into()]);
+                    }
+                    for value in &cli.overrides {
+                        args.extend(["--set".into(), value.into()]);
+                    }
+                    watch_day(year, day, input.as_deref(), &args)
+                })
+        }
+        Command::Tui { year, cache_dir } => runs::Runs::load(&runs::runs_path(&cache_dir, year))
+            .and_then(|runs| tui::App::new(year, params, runs))
+            .and_then(tui::run),
+        Command::Report { cache_dir, output } => report(&cache_dir, &output, &params).map(|()| {
+            println!("Report written to {}", output.display());
+        }),
+        Command::New { day, year } => {
+            scaffold::new_day(aoc::workspace_dir(), year, day).map(|path| {
+                let path = path.strip_prefix(aoc::workspace_dir()).unwrap_or(&path);
+                println!("Day {day} of {year} created in {}", path.display());
+            })
+        }
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(
+    year: u16,
+    day: u8,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    format: Format,
+    params: &Params,
+) -> Result<()> {
+    let path = input.unwrap_or_else(|| aoc::default_input(year, day));
+    let input = read_input(&path)?;
+    for (part, solver) in parts(year, day, part)? {
+        let start = Instant::now();
+        let (answer, usage) = alloc::measure(|| solver(&input, params));
+        let elapsed = start.elapsed();
+        let answer = answer.map_err(|err| err.with_file(source(&path)))?;
+        let usage = cfg!(feature = "alloc-stats").then_some(usage);
+        match format {
+            Format::Text => match usage {
+                Some(usage) => println!("Day {day} part {part}: {answer} ({usage})"),
+                None => println!("Day {day} part {part}: {answer}"),
+            },
+            Format::Json => {
+                let record = Record {
+                    day,
+                    part,
+                    input: source(&path),
+                    answer,
+                    elapsed: elapsed.as_secs_f64(),
+                    peak_bytes: usage.map(|usage| usage.peak_bytes),
+                    allocations: usage.map(|usage| usage.allocations),
+                };
+                println!("{}", serde_json::to_string(&record)?);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn run_all(jobs: Option<u16>, cache_dir: &Path, params: &Params) -> Result<()> {
+    let threads = jobs.map_or_else(
+        || thread::available_parallelism().map_or(1, |threads| threads.get()),
+        usize::from,
+    );
+    let outcomes = run_all::run(run_all::jobs(params)?, threads);
+    print!("{}", run_all::table(&outcomes));
+    let mut runs = runs::Runs::load(&runs::runs_path(cache_dir, aoc::YEAR))?;
+    for outcome in &outcomes {
+        if let Some(answer) = &outcome.answer {
+            if outcome.input == aoc::default_input(aoc::YEAR, outcome.day) {
+                let run = runs::Run {
+                    answer: answer.clone(),
+                    elapsed: outcome.elapsed,
+                };
+                runs.record(outcome.day, outcome.part, run)?;
+            }
+        }
+    }
+    let failures = outcomes
+        .iter()
+        .filter(|outcome| outcome.status.is_failure())
+        .count();
+    if failures > 0 {
+        return Err(Error::other(format!(
+            "{failures} of {} runs failed",
+            outcomes.len()
+        )));
+    }
+    Ok(())
+}
+
+fn report(cache_dir: &Path, output: &Path, params: &Params) -> Result<()> {
+    let runs = runs::Runs::load(&runs::runs_path(cache_dir, aoc::YEAR))?;
+    let html = report::report(&runs, params)?;
+    fs::write(output, html)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", output.display())))
+}
+
+#[cfg(target_os = "linux")]
+fn watch_day(year: u16, day: u8, input: Option<&Path>, args: &[OsString]) -> Result<()> {
+    aoc::watch::watch(year, day, input, args)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn watch_day(_: u16, _: u8, _: Option<&Path>, _: &[OsString]) -> Result<()> {
+    Err(Error::new(
+        ErrorKind::Unsupported,
+        "Watching files needs inotify, which only Linux has",
+    ))
+}
+
+fn bench(
+    year: u16,
+    day: Option<u8>,
+    part: Option<u8>,
+    input: Option<PathBuf>,
+    runs: usize,
+    json: Option<PathBuf>,
+    params: &Params,
+) -> Result<()> {
+    let mut timings = Vec::new();
+    for day in day.map_or(1..=25, |day| day..=day) {
+        let path = input
+            .clone()
+            .unwrap_or_else(|| aoc::default_input(year, day));
+        if input.is_none() && !path.exists() {
+            eprintln!("Skipping day {day}: {} is missing", path.display());
+            continue;
+        }
+        let content = read_input(&path)?;
+        let name = source(&path).display().to_string();
+        for (part, solver) in parts(year, day, part)? {
+            let timing = bench::measure(day, part, solver, &name, &content, params, runs)
+                .map_err(|err| err.with_file(source(&path)))?;
+            println!(
+                "Day {day:>2} part {part}: min {:>10.2?}, median {:>10.2?}, max {:>10.2?}",
+                timing.min(),
+                timing.median(),
+                timing.max()
+            );
+            timings.push(timing);
+        }
+    }
+    if let Some(json) = json {
+        let report = serde_json::to_string_pretty(&timings)? + "\n";
+        fs::write(&json, report)
+            .map_err(|err| Error::new(err.kind(), format!("{}: {err}", json.display())))?;
+    }
+    Ok(())
+}
+
+fn check(year: u16, day: u8, input: Option<PathBuf>, params: &Params) -> Result<()> {
+    let solvers = days::get_in(year, day).ok_or(Error::new(
+        ErrorKind::NotFound,
+        format!("Day {day} of {year} not found"),
+    ))?;
+    let path = input.unwrap_or_else(|| aoc::default_input(year, day));
+    let problems = (solvers.check)(&read_input(&path)?, params);
+    for problem in problems.iter() {
+        println!("{}", problem.clone().with_file(source(&path)));
+    }
+    if !problems.is_empty() {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "{} problem{} in {}",
+                problems.len(),
+                if problems.len() == 1 { "" } else { "s" },
+                source(&path).display()
+            ),
+        ));
+    }
+    println!("Day {day}: {} looks fine", source(&path).display());
+    Ok(())
+}
+
+fn generate(day: u8, size: Option<usize>, seed: Option<u64>) -> Result<()> {
+    let generator = generators::get(day).ok_or(Error::new(ErrorKind::NotFound, "Day not found"))?;
+    let seed = seed.unwrap_or_else(|| {
+        let seed = generators::Rng::new().u64(..);
+        eprintln!("Seed: {seed}");
+        seed
+    });
+    let size = size.unwrap_or(generator.default_size);
+    print!(
+        "{}",
+        (generator.generate)(&mut generators::Rng::with_seed(seed), size)
+    );
+    Ok(())
+}
+
+fn differential(
+    day: Option<u8>,
+    seeds: Range<u64>,
+    size: Option<usize>,
+    params: &Params,
+) -> Result<()> {
+    let pairs: Vec<&differential::Pair> = match day {
+        Some(day) => vec![differential::get(day).ok_or(Error::new(
+            ErrorKind::NotFound,
+            "Day has no second algorithm to compare with",
+        ))?],
+        None => differential::PAIRS.iter().collect(),
+    };
+    let mut disagreements = 0;
+    for pair in pairs.iter() {
+        let generator = generators::get(pair.day).expect("Every day has a generator");
+        let size = size.unwrap_or(generator.default_size);
+        match differential::check(pair, seeds.clone(), size, params) {
+            differential::Outcome::Agree => println!(
+                "Day {} {}: {} inputs agree",
+                pair.day,
+                pair.name,
+                seeds.end - seeds.start
+            ),
+            differential::Outcome::NoAnswers => {
+                disagreements += 1;
+                println!(
+                    "Day {} {}: no answer on any of the {} inputs, nothing compared",
+                    pair.day,
+                    pair.name,
+                    seeds.end - seeds.start
+                );
+            }
+            differential::Outcome::Disagree(disagreement) => {
+                disagreements += 1;
+                print!(
+                    "Day {} {}: seed {} disagrees, {}. Minimised input:\n{}",
+                    pair.day, pair.name, disagreement.seed, disagreement.reason, disagreement.input
+                );
+            }
+        }
+    }
+    if disagreements > 0 {
+        return Err(Error::other(format!(
+            "{disagreements} of {} days disagree or compare nothing on generated inputs",
+            pairs.len()
+        )));
+    }
+    Ok(())
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    input: Option<PathBuf>,
+    year: u16,
+    cache_dir: &Path,
+    remote: &Remote,
+    params: &Params,
+) -> Result<()> {
+    let path = input.unwrap_or_else(|| aoc::default_input(year, day));
+    let input = read_input(&path)?;
+    let (_, solver) = parts(year, day, Some(part))?.remove(0);
+    let answer = solver(&input, params).map_err(|err| err.with_file(source(&path)))?;
+    let mut history = submit::History::load(&submit::history_path(cache_dir, year))?;
+    let verdict = submit::submit(&remote.client(), &mut history, year, day, part, &answer)?;
+    println!("Day {day} part {part}: {answer} is {verdict}");
+    Ok(())
+}
+
+/// The solver of `part`, or of every part of `day` when it is `None`.
+fn parts(year: u16, day: u8, part: Option<u8>) -> Result<Vec<(u8, days::Solver)>> {
+    let solvers = days::get_in(year, day).ok_or(Error::new(
+        ErrorKind::NotFound,
+        format!("Day {day} of {year} not found"),
+    ))?;
+    let parts = match part {
+        Some(part) => vec![part],
+        None => (1..=solvers.parts.len() as u8).collect(),
+    };
+    parts
+        .into_iter()
+        .map(|part| {
+            let solver = solvers.part(part).ok_or(Error::new(
+                ErrorKind::NotFound,
+                format!("Day {day} has no part {part}"),
+            ))?;
+            Ok((part, solver))
+        })
+        .collect()
+}
+
+/// Reads the puzzle input from `path`, or from stdin when it is `-`.
+fn read_input(path: &Path) -> Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+    fs::read_to_string(path)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+/// How the input at `path` is named in errors and reports.
+fn source(path: &Path) -> &Path {
+    if path.as_os_str() == "-" {
+        Path::new("<stdin>")
+    } else {
+        path
+    }
+}
@@ -0,0 +1,43 @@
+//! The final state of the days played on a map, drawn as text for the terminal UI and reports.
+
+use crate::{days::SolveError, params::Params};
+use common::Puzzle;
+
+/// Solves the day on the input and draws where it ends up, with the puzzle constants of `Params`.
+pub type Renderer = fn(&str, &Params) -> Result<String, SolveError>;
+
+/// The days with a map: the guard's path, the robots drawing the tree, both warehouses, the best
+/// seats, the path out of the corrupted memory and the cheats of the race.
+pub const DAYS: [u8; 6] = [6, 14, 15, 16, 18, 20];
+
+pub fn get(day: u8) -> Option<Renderer> {
+    Some(match day {
+        6 => |input, _| Ok(day6::draw_path(&day6::Day6::parse(input)?)),
+        14 => |input, params| {
+            let map_size = (params.day14.width, params.day14.height);
+            let robots = day14::read_input(input, map_size)?;
+            let seconds = day14::find_christmas_tree(&robots, map_size);
+            Ok(day14::draw_map(&robots, map_size, seconds as usize))
+        },
+        15 => |input, _| {
+            let warehouse = day15::Day15::parse(input)?;
+            Ok(day15::draw_after_moves(&warehouse)
+                + "\n"
+                + &day15::draw_wide_after_moves(&warehouse))
+        },
+        16 => |input, _| Ok(day16::draw_best_paths(&day16::Day16::parse(input)?)),
+        18 => |input, params| {
+            let day18 = &params.day18;
+            let bytes = day18::read_input(input, day18.grid_size)?;
+            Ok(day18::draw_memory(&bytes, day18.grid_size, day18.bytes))
+        },
+        20 => |input, params| {
+            let racetrack = day20::Day20::parse(input)?;
+            Ok(day20::draw_short_cheats(
+                &racetrack,
+                params.day20.part1_min_saving,
+            ))
+        },
+        _ => return None,
+    })
+}
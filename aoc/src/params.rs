@@ -0,0 +1,252 @@
+//! The constants of the puzzles, so examples and what-if runs need no code edits.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    ops::RangeInclusive,
+    path::Path,
+};
+use toml::Table;
+
+/// Every parameter, each defaulting to the value of the real puzzle.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub day11: Day11,
+    pub day14: Day14,
+    pub day18: Day18,
+    pub day20: Day20,
+    pub day21: Day21,
+    pub day22: Day22,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day11 {
+    pub part1_blinks: usize,
+    pub part2_blinks: usize,
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        Day11 {
+            part1_blinks: day11::PART1_BLINKS,
+            part2_blinks: day11::PART2_BLINKS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day14 {
+    pub width: usize,
+    pub height: usize,
+    /// How long the robots walk before the safety factor is measured
+    pub seconds: usize,
+}
+
+impl Default for Day14 {
+    fn default() -> Self {
+        Day14 {
+            width: day14::MAP_SIZE.0,
+            height: day14::MAP_SIZE.1,
+            seconds: day14::SECONDS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day18 {
+    /// The largest coordinate of the memory space
+    pub grid_size: usize,
+    /// How many bytes have fallen in the first part
+    pub bytes: usize,
+}
+
+impl Default for Day18 {
+    fn default() -> Self {
+        Day18 {
+            grid_size: day18::GRID_SIZE,
+            bytes: day18::BYTES,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day20 {
+    /// Picoseconds a cheat must save to be counted
+    pub part1_min_saving: u64,
+    pub part2_min_saving: u64,
+}
+
+impl Default for Day20 {
+    fn default() -> Self {
+        Day20 {
+            part1_min_saving: day20::MIN_SAVING,
+            part2_min_saving: day20::MIN_SAVING,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day21 {
+    /// Robots using a directional keypad between the person and the numeric keypad
+    pub part1_robots: usize,
+    pub part2_robots: usize,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Day21 {
+            part1_robots: day21::PART1_ROBOTS,
+            part2_robots: day21::PART2_ROBOTS,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Day22 {
+    /// New secret numbers of each buyer
+    pub iterations: usize,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Day22 {
+            iterations: day22::ITERATIONS,
+        }
+    }
+}
+
+impl Params {
+    /// These parameters, fitted to the inputs made by the generators with `size`: day 18 uses the
+    /// whole memory space generated, and the bytes of its first quarter in the first part.
+    pub fn generated(&self, size: usize) -> Params {
+        let side = generators::day18_side(size);
+        let mut params = self.clone();
+        params.day18.grid_size = side - 1;
+        params.day18.bytes = side * side / 4;
+        params
+    }
+
+    /// Reads the TOML file at `path`, if any, then applies every `key=value` override on top of
+    /// it, e.g. `day14.seconds=50`. Values the solvers cannot run with are rejected.
+    pub fn load(path: Option<&Path>, overrides: &[String]) -> Result<Params> {
+        let mut config = match path {
+            Some(path) => {
+                let with_path = |err: String| {
+                    Error::new(ErrorKind::InvalidData, format!("{}: {err}", path.display()))
+                };
+                let config = fs::read_to_string(path).map_err(|err| with_path(err.to_string()))?;
+                config
+                    .parse::<Table>()
+                    .map_err(|err| with_path(err.to_string()))?
+            }
+            None => Table::new(),
+        };
+        for value in overrides {
+            let invalid = |err: String| {
+                Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("Override `{value}`: {err}"),
+                )
+            };
+            if !value.contains('=') {
+                return Err(invalid("expected `key=value`".to_string()));
+            }
+            let value = value
+                .parse::<Table>()
+                .map_err(|err| invalid(err.to_string()))?;
+            merge(&mut config, value);
+        }
+        let params: Params = config
+            .try_into()
+            .map_err(|err: toml::de::Error| Error::new(ErrorKind::InvalidData, err.to_string()))?;
+        params.validate()?;
+        Ok(params)
+    }
+
+    /// The sizes the solvers need: a space to walk in, at least one robot, and no answer too
+    /// large to count. The first part of day 11 keeps every stone in memory, so it blinks less.
+    fn validate(&self) -> Result<()> {
+        within("day11.part1_blinks", self.day11.part1_blinks, 0..=40)?;
+        within("day11.part2_blinks", self.day11.part2_blinks, 0..=90)?;
+        within("day14.width", self.day14.width, 1..=10_000)?;
+        within("day14.height", self.day14.height, 1..=10_000)?;
+        within("day18.grid_size", self.day18.grid_size, 0..=10_000)?;
+        within("day21.part1_robots", self.day21.part1_robots, 1..=30)?;
+        within("day21.part2_robots", self.day21.part2_robots, 1..=30)
+    }
+}
+
+fn within(key: &str, value: usize, range: RangeInclusive<usize>) -> Result<()> {
+    if range.contains(&value) {
+        return Ok(());
+    }
+    Err(Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "`{key}` must be between {} and {}, not {value}",
+            range.start(),
+            range.end()
+        ),
+    ))
+}
+
+/// Copies every value of `overrides` into `config`, keeping the other keys of nested tables.
+fn merge(config: &mut Table, overrides: Table) {
+    for (key, value) in overrides {
+        match (config.get_mut(&key), value) {
+            (Some(toml::Value::Table(table)), toml::Value::Table(value)) => merge(table, value),
+            (_, value) => {
+                config.insert(key, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_win_over_the_file_and_defaults_fill_the_rest() {
+        let path = std::env::temp_dir().join(format!("aoc-params-{}.toml", std::process::id()));
+        fs::write(&path, "[day14]\nwidth = 11\nheight = 7\n").unwrap();
+        let params = Params::load(Some(&path), &["day14.height=9".to_string()]).unwrap();
+        assert_eq!(params.day14.width, 11);
+        assert_eq!(params.day14.height, 9);
+        assert_eq!(params.day14.seconds, 100);
+        assert_eq!(params.day18, Day18::default());
+    }
+
+    #[test]
+    fn rejects_unknown_parameters() {
+        assert!(Params::load(None, &["day14.widht=11".to_string()]).is_err());
+        assert!(Params::load(None, &["day14.width".to_string()]).is_err());
+        assert!(Params::load(None, &["day14.width=-1".to_string()]).is_err());
+    }
+
+    #[test]
+    fn rejects_sizes_the_solvers_cannot_run_with() {
+        let err = Params::load(None, &["day14.width=0".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`day14.width` must be between 1 and 10000, not 0"
+        );
+        assert!(Params::load(None, &["day21.part2_robots=0".to_string()]).is_err());
+        assert!(Params::load(None, &["day18.grid_size=100000".to_string()]).is_err());
+        assert!(Params::load(None, &["day18.grid_size=0".to_string()]).is_ok());
+        let err = Params::load(None, &["day11.part1_blinks=41".to_string()]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "`day11.part1_blinks` must be between 0 and 40, not 41"
+        );
+        assert!(Params::load(None, &["day11.part2_blinks=75".to_string()]).is_ok());
+    }
+}
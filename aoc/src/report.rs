@@ -0,0 +1,221 @@
+//! `aoc report`: one self-contained HTML page with the last answer and the timing history of every
+//! part, and the maps of a few days drawn as SVG, to share the progress without running anything.
+
+use crate::{
+    answers,
+    days::{self, SolveError},
+    default_input, maps,
+    params::Params,
+    runs::Runs,
+    YEAR,
+};
+use common::Puzzle;
+use std::{fmt::Write, fs, io};
+
+/// Side of one tile of a map, in pixels.
+const TILE: usize = 6;
+
+/// A map drawn from the real input of a day.
+struct Drawing {
+    day: u8,
+    title: &'static str,
+    draw: fn(&str, &Params) -> Result<String, SolveError>,
+}
+
+const DRAWINGS: [Drawing; 3] = [
+    Drawing {
+        day: 12,
+        title: "Garden regions",
+        draw: |input, _| {
+            let regions = day12::regions(&day12::Day12::parse(input)?);
+            let tiles = regions
+                .iter()
+                .map(|(plot, region)| (plot.x, plot.y, colour(*region)));
+            Ok(tiles_svg(regions.width(), regions.height(), tiles))
+        },
+    },
+    Drawing {
+        day: 14,
+        title: "Christmas tree",
+        draw: |input, params| {
+            let map = (maps::get(14).expect("Day 14 has a map"))(input, params)?;
+            Ok(text_svg(&map, |tile| (tile == '█').then_some("#2e7d32")))
+        },
+    },
+    Drawing {
+        day: 16,
+        title: "Best seats",
+        draw: |input, params| {
+            let map = (maps::get(16).expect("Day 16 has a map"))(input, params)?;
+            Ok(text_svg(&map, |tile| match tile {
+                '#' => Some("#455a64"),
+                'O' => Some("#f9a825"),
+                _ => None,
+            }))
+        },
+    },
+];
+
+/// The whole page. Days without an input get a note instead of their drawing.
+pub fn report(runs: &Runs, params: &Params) -> io::Result<String> {
+    let expected = answers::expected(YEAR)?;
+    let mut html = String::new();
+    html.push_str(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n",
+        "<title>Advent of Code</title>\n<style>\n",
+        "body { font-family: sans-serif; margin: 2em; }\n",
+        "table { border-collapse: collapse; }\n",
+        "td, th { padding: 0.2em 0.8em; text-align: left; }\n",
+        "tr:nth-child(even) { background: #f0f0f0; }\n",
+        ".correct { color: #2e7d32; }\n.wrong { color: #c62828; }\n",
+        "</style>\n</head>\n<body>\n",
+    ));
+    let _ = writeln!(html, "<h1>Advent of Code {YEAR}</h1>");
+    html.push_str("<table>\n<tr><th>Day</th><th>Part</th><th>Answer</th><th>Time</th>");
+    html.push_str("<th>History</th></tr>\n");
+    for day in days::DAYS.iter() {
+        for part in (1..).take(day.parts.len()) {
+            let (answer, elapsed) = match runs.get(day.number, part) {
+                Some(run) => {
+                    let class = match expected.get(&(day.number, part)) {
+                        Some(expected) if *expected == run.answer => "correct",
+                        Some(_) => "wrong",
+                        None => "unchecked",
+                    };
+                    let answer = format!("<span class=\"{class}\">{}</span>", escape(&run.answer));
+                    (answer, format!("{:.2?}", run.elapsed))
+                }
+                None => ("-".to_string(), String::new()),
+            };
+            let _ = writeln!(
+                html,
+                "<tr><td>{}</td><td>{part}</td><td>{answer}</td><td>{elapsed}</td><td>{}</td></tr>",
+                day.number,
+                sparkline(runs, day.number, part),
+            );
+        }
+    }
+    html.push_str("</table>\n");
+    for drawing in &DRAWINGS {
+        let _ = writeln!(html, "<h2>Day {}: {}</h2>", drawing.day, drawing.title);
+        let path = default_input(YEAR, drawing.day);
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                html.push_str("<p>No input.</p>\n");
+                continue;
+            }
+            Err(err) => {
+                return Err(io::Error::new(
+                    err.kind(),
+                    format!("{}: {err}", path.display()),
+                ))
+            }
+        };
+        let svg = (drawing.draw)(&input, params).map_err(|err| err.with_file(&path))?;
+        html.push_str(&svg);
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(html)
+}
+
+/// The time of every run of the part, oldest on the left, scaled to the slowest one.
+fn sparkline(runs: &Runs, day: u8, part: u8) -> String {
+    let history = runs.history(day, part);
+    let (width, height) = (120, 20);
+    let Some(slowest) = history.iter().map(|timing| timing.elapsed).max() else {
+        return String::new();
+    };
+    let scale = height as f64 / slowest.as_secs_f64().max(f64::MIN_POSITIVE);
+    let step = width as f64 / history.len().saturating_sub(1).max(1) as f64;
+    let points: Vec<String> = history
+        .iter()
+        .enumerate()
+        .map(|(index, timing)| {
+            let y = height as f64 - timing.elapsed.as_secs_f64() * scale;
+            format!("{:.1},{:.1}", index as f64 * step, y)
+        })
+        .collect();
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{}\" \
+         viewBox=\"0 -1 {width} {}\"><title>{} runs, slowest {slowest:.2?}</title>\
+         <polyline points=\"{}\" fill=\"none\" stroke=\"#1565c0\"/></svg>",
+        height + 2,
+        height + 2,
+        history.len(),
+        points.join(" "),
+    )
+}
+
+/// A text map, one square per tile given a colour.
+fn text_svg(map: &str, colour: impl Fn(char) -> Option<&'static str>) -> String {
+    let width = map
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+    let height = map.lines().count();
+    let colour = &colour;
+    let tiles = map.lines().enumerate().flat_map(|(y, line)| {
+        line.chars()
+            .enumerate()
+            .filter_map(move |(x, tile)| Some((x, y, colour(tile)?.to_string())))
+    });
+    tiles_svg(width, height, tiles)
+}
+
+fn tiles_svg(
+    width: usize,
+    height: usize,
+    tiles: impl Iterator<Item = (usize, usize, String)>,
+) -> String {
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"#fafafa\"/>\n",
+        width * TILE,
+        height * TILE,
+    );
+    for (x, y, colour) in tiles {
+        let _ = writeln!(
+            svg,
+            "<rect x=\"{x}\" y=\"{y}\" width=\"1\" height=\"1\" fill=\"{colour}\"/>"
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Neighbouring indices get hues far apart.
+fn colour(index: usize) -> String {
+    format!("hsl({}, 60%, 65%)", index * 137 % 360)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn draws_a_square_per_coloured_tile() {
+        let svg = text_svg("#.\n.O\n", |tile| match tile {
+            '#' => Some("black"),
+            'O' => Some("gold"),
+            _ => None,
+        });
+        assert_eq!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"12\" \
+             viewBox=\"0 0 2 2\" shape-rendering=\"crispEdges\">\n\
+             <rect width=\"2\" height=\"2\" fill=\"#fafafa\"/>\n\
+             <rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"black\"/>\n\
+             <rect x=\"1\" y=\"1\" width=\"1\" height=\"1\" fill=\"gold\"/>\n\
+             </svg>\n"
+        );
+    }
+}
@@ -0,0 +1,299 @@
+//! `aoc run --all`: every example and real input of every day, on worker threads.
+
+use crate::{
+    answers, days, days::Solver, default_input, input_path, params::Params, workspace_dir,
+};
+use std::{
+    any::Any,
+    cell::Cell,
+    fs, io,
+    panic::{self, AssertUnwindSafe},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, Once,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+/// One part to solve on one input.
+pub struct Job {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub expected: Option<String>,
+    pub params: Params,
+    pub solver: Solver,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer of the registry
+    Correct,
+    Wrong {
+        expected: String,
+    },
+    /// Nothing to compare with, as the registry has no answer for this input
+    Unchecked,
+    Failed(String),
+    Panicked(String),
+}
+
+impl Status {
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Wrong { .. } | Status::Failed(_) | Status::Panicked(_)
+        )
+    }
+}
+
+pub struct Outcome {
+    pub day: u8,
+    pub part: u8,
+    pub input: PathBuf,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+/// Every registered example whose file is present, then the real input of each day when there is
+/// one. Real inputs use `params`, examples their own parameter file.
+pub fn jobs(params: &Params) -> io::Result<Vec<Job>> {
+    let mut jobs = Vec::new();
+    for answer in answers::load()? {
+        let path = input_path(answer.day, &answer.input);
+        let Some(solver) = days::get(answer.day).and_then(|day| day.part(answer.part)) else {
+            continue;
+        };
+        if !path.exists() {
+            continue;
+        }
+        let params = match &answer.params {
+            Some(file) => Params::load(Some(&input_path(answer.day, file)), &[])?,
+            None => params.clone(),
+        };
+        jobs.push(Job {
+            day: answer.day,
+            part: answer.part,
+            input: path,
+            expected: Some(answer.expected),
+            params,
+            solver,
+        });
+    }
+    for day in days::DAYS.iter() {
+        let path = default_input(crate::YEAR, day.number);
+        if !path.exists() {
+            continue;
+        }
+        for (part, solver) in (1..).zip(day.parts) {
+            let registered = jobs
+                .iter()
+                .any(|job| job.day == day.number && job.part == part && job.input == path);
+            if !registered {
+                jobs.push(Job {
+                    day: day.number,
+                    part,
+                    input: path.clone(),
+                    expected: None,
+                    params: params.clone(),
+                    solver: *solver,
+                });
+            }
+        }
+    }
+    Ok(jobs)
+}
+
+/// Runs `jobs` on `threads` workers, sorted by day, part and input. A job that fails or panics only
+/// marks its own outcome.
+pub fn run(jobs: Vec<Job>, threads: usize) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let outcomes = Mutex::new(Vec::with_capacity(jobs.len()));
+    thread::scope(|scope| {
+        for _ in 0..threads.max(1) {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    let outcome = run_job(job);
+                    outcomes.lock().unwrap().push(outcome);
+                }
+            });
+        }
+    });
+    let mut outcomes = outcomes.into_inner().unwrap();
+    outcomes.sort_by(|a, b| (a.day, a.part, &a.input).cmp(&(b.day, b.part, &b.input)));
+    outcomes
+}
+
+fn run_job(job: &Job) -> Outcome {
+    let start = Instant::now();
+    let result = fs::read_to_string(&job.input)
+        .map_err(|err| Status::Failed(err.to_string()))
+        .and_then(|input| {
+            // The panics are reported in the table, not as they happen
+            match isolated(|| (job.solver)(&input, &job.params)) {
+                Ok(Ok(answer)) => Ok(answer),
+                Ok(Err(err)) => Err(Status::Failed(err.to_string())),
+                Err(message) => Err(Status::Panicked(message)),
+            }
+        });
+    let elapsed = start.elapsed();
+    let (answer, status) = match result {
+        Ok(answer) => {
+            let status = match &job.expected {
+                Some(expected) if *expected == answer => Status::Correct,
+                Some(expected) => Status::Wrong {
+                    expected: expected.clone(),
+                },
+                None => Status::Unchecked,
+            };
+            (Some(answer), status)
+        }
+        Err(status) => (None, status),
+    };
+    Outcome {
+        day: job.day,
+        part: job.part,
+        input: job.input.clone(),
+        answer,
+        status,
+        elapsed,
+    }
+}
+
+thread_local! {
+    /// Whether the thread runs inside [`isolated`].
+    static ISOLATING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f` and returns the message of its panic instead of printing it. The first call installs a
+/// panic hook for good, that stays quiet on the threads inside `isolated` and hands the panics of
+/// every other thread to the previous hook, so that concurrent calls never lose a panic.
+pub(crate) fn isolated<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATING.get() {
+                previous(info);
+            }
+        }));
+    });
+    let was_isolating = ISOLATING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATING.set(was_isolating);
+    result.map_err(panic_message)
+}
+
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    if let Some(message) = panic.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = panic.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// The outcomes as an aligned table, with inputs relative to the workspace.
+pub fn table(outcomes: &[Outcome]) -> String {
+    let header = ["Day", "Part", "Input", "Answer", "Status", "Time"].map(str::to_string);
+    let rows: Vec<[String; 6]> = outcomes
+        .iter()
+        .map(|outcome| {
+            let input = outcome
+                .input
+                .strip_prefix(workspace_dir())
+                .unwrap_or(&outcome.input);
+            [
+                outcome.day.to_string(),
+                outcome.part.to_string(),
+                input.display().to_string(),
+                outcome.answer.clone().unwrap_or_default(),
+                match &outcome.status {
+                    Status::Correct => "ok".to_string(),
+                    Status::Wrong { expected } => format!("WRONG, expected {expected}"),
+                    Status::Unchecked => "-".to_string(),
+                    Status::Failed(err) => format!("FAILED: {err}"),
+                    Status::Panicked(message) => format!("PANICKED: {message}"),
+                },
+                format!("{:.2?}", outcome.elapsed),
+            ]
+        })
+        .collect();
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in rows.iter() {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    let mut table = String::new();
+    for row in [header].iter().chain(rows.iter()) {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect();
+        table += cells.join("  ").trim_end();
+        table.push('\n');
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::ParseError;
+
+    #[test]
+    fn isolation_ends_with_the_call() {
+        let nested = isolated(|| {
+            let inner: Result<(), String> = isolated(|| panic!("inner"));
+            (inner, ISOLATING.get())
+        });
+        assert_eq!(nested, Ok((Err("inner".to_string()), true)));
+        assert!(!ISOLATING.get());
+    }
+
+    fn job(part: u8, expected: Option<&str>, solver: Solver) -> Job {
+        Job {
+            day: 1,
+            part,
+            input: PathBuf::from(concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml")),
+            expected: expected.map(str::to_string),
+            params: Params::default(),
+            solver,
+        }
+    }
+
+    #[test]
+    fn failures_and_panics_stay_in_their_row() {
+        let jobs = vec![
+            job(5, None, |_, _| panic!("boom")),
+            job(4, None, |_, _| {
+                Err(ParseError::new(1, 1, "a number", "`x`").into())
+            }),
+            job(3, Some("2"), |_, _| Ok("3".to_string())),
+            job(2, None, |_, _| Ok("2".to_string())),
+            job(1, Some("1"), |_, _| Ok("1".to_string())),
+        ];
+        let outcomes = run(jobs, 3);
+        let statuses: Vec<&Status> = outcomes.iter().map(|outcome| &outcome.status).collect();
+        assert_eq!(statuses.len(), 5);
+        assert_eq!(statuses[0], &Status::Correct);
+        assert_eq!(statuses[1], &Status::Unchecked);
+        assert_eq!(
+            statuses[2],
+            &Status::Wrong {
+                expected: "2".to_string()
+            }
+        );
+        assert!(matches!(statuses[3], Status::Failed(_)));
+        assert_eq!(statuses[4], &Status::Panicked("boom".to_string()));
+        let table = table(&outcomes);
+        assert_eq!(table.lines().count(), 6);
+        assert!(table.contains("aoc/Cargo.toml"), "{table}");
+    }
+}
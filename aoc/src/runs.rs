@@ -0,0 +1,217 @@
+//! The last answer and time of every part run from the terminal UI or `aoc run --all`, so that they
+//! are still shown on the next start, and the time of every run before it for `aoc report`.
+
+use common::OrExpected;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Error, ErrorKind, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub answer: String,
+    pub elapsed: Duration,
+}
+
+/// When a part was run and how long it took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timing {
+    pub at: SystemTime,
+    pub elapsed: Duration,
+}
+
+/// The last run of each part of one year, kept in a file with one
+/// `<day> <part> <nanoseconds> <answer>` line per part. Every run is also appended to
+/// `history.txt` next to it, as a `<unix seconds> <day> <part> <nanoseconds>` line.
+pub struct Runs {
+    path: PathBuf,
+    runs: BTreeMap<(u8, u8), Run>,
+    history: BTreeMap<(u8, u8), Vec<Timing>>,
+}
+
+/// Where the runs of `year` are kept inside `cache_dir`.
+pub fn runs_path(cache_dir: &Path, year: u16) -> PathBuf {
+    cache_dir.join(year.to_string()).join("runs.txt")
+}
+
+impl Runs {
+    /// No runs when the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<Runs> {
+        let history_path = path.with_file_name("history.txt");
+        Ok(Runs {
+            path: path.to_path_buf(),
+            runs: read(path, parse)?.unwrap_or_default(),
+            history: read(&history_path, parse_history)?.unwrap_or_default(),
+        })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Run> {
+        self.runs.get(&(day, part))
+    }
+
+    /// Every run of the part, oldest first.
+    pub fn history(&self, day: u8, part: u8) -> &[Timing] {
+        self.history.get(&(day, part)).map_or(&[], Vec::as_slice)
+    }
+
+    /// Replaces the last run of the part, rewrites the file and appends the run to the history.
+    pub fn record(&mut self, day: u8, part: u8, run: Run) -> io::Result<()> {
+        let timing = Timing {
+            at: SystemTime::now(),
+            elapsed: run.elapsed,
+        };
+        self.runs.insert((day, part), run);
+        self.history.entry((day, part)).or_default().push(timing);
+        let history_path = self.path.with_file_name("history.txt");
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(|err| in_file(&self.path, err))?;
+        }
+        let seconds = timing
+            .at
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&history_path)
+            .and_then(|mut history| {
+                writeln!(
+                    history,
+                    "{seconds} {day} {part} {}",
+                    timing.elapsed.as_nanos()
+                )
+            })
+            .map_err(|err| in_file(&history_path, err))?;
+        let lines: String = self
+            .runs
+            .iter()
+            .map(|((day, part), run)| {
+                format!("{day} {part} {} {}\n", run.elapsed.as_nanos(), run.answer)
+            })
+            .collect();
+        fs::write(&self.path, lines).map_err(|err| in_file(&self.path, err))
+    }
+}
+
+/// `None` when the file does not exist yet.
+fn read<T>(path: &Path, parse: fn(&str) -> common::Result<T>) -> io::Result<Option<T>> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(parse(&content).map_err(|err| err.with_file(path))?)),
+        Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(in_file(path, err)),
+    }
+}
+
+fn in_file(path: &Path, err: Error) -> Error {
+    Error::new(err.kind(), format!("{}: {err}", path.display()))
+}
+
+fn parse(runs: &str) -> common::Result<BTreeMap<(u8, u8), Run>> {
+    runs.lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(4, ' ');
+            let mut next_field = |expected: &str| {
+                fields
+                    .next()
+                    .or_expected(runs, &line[line.len()..], expected)
+            };
+            let day = next_field("a day")?;
+            let part = next_field("a part")?;
+            let nanos = next_field("a time in nanoseconds")?;
+            let answer = next_field("an answer")?;
+            Ok((
+                (
+                    day.parse().or_expected(runs, day, "a day")?,
+                    part.parse().or_expected(runs, part, "a part")?,
+                ),
+                Run {
+                    answer: answer.to_string(),
+                    elapsed: Duration::from_nanos(nanos.parse().or_expected(
+                        runs,
+                        nanos,
+                        "a time in nanoseconds",
+                    )?),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn parse_history(history: &str) -> common::Result<BTreeMap<(u8, u8), Vec<Timing>>> {
+    let mut timings: BTreeMap<(u8, u8), Vec<Timing>> = BTreeMap::new();
+    for line in history.lines().filter(|line| !line.trim().is_empty()) {
+        let mut fields = line.split(' ');
+        let mut next_field = |expected: &str| {
+            fields
+                .next()
+                .or_expected(history, &line[line.len()..], expected)
+        };
+        let seconds = next_field("a time in seconds")?;
+        let day = next_field("a day")?;
+        let part = next_field("a part")?;
+        let nanos = next_field("a time in nanoseconds")?;
+        let at = UNIX_EPOCH
+            + Duration::from_secs(seconds.parse().or_expected(
+                history,
+                seconds,
+                "a time in seconds",
+            )?);
+        let elapsed = Duration::from_nanos(nanos.parse().or_expected(
+            history,
+            nanos,
+            "a time in nanoseconds",
+        )?);
+        timings
+            .entry((
+                day.parse().or_expected(history, day, "a day")?,
+                part.parse().or_expected(history, part, "a part")?,
+            ))
+            .or_default()
+            .push(Timing { at, elapsed });
+    }
+    Ok(timings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn keeps_the_last_run_of_each_part() {
+        let dir = env::temp_dir().join(format!("aoc-runs-{}", std::process::id()));
+        let path = runs_path(&dir, 2024);
+        let mut runs = Runs::load(&path).unwrap();
+        assert_eq!(runs.get(25, 1), None);
+        let run = |answer: &str, nanos| Run {
+            answer: answer.to_string(),
+            elapsed: Duration::from_nanos(nanos),
+        };
+        runs.record(25, 1, run("3", 10)).unwrap();
+        runs.record(23, 2, run("co,de,ka,ta", 20)).unwrap();
+        runs.record(25, 1, run("4", 30)).unwrap();
+        let runs = Runs::load(&path).unwrap();
+        assert_eq!(runs.get(25, 1), Some(&run("4", 30)));
+        assert_eq!(runs.get(23, 2), Some(&run("co,de,ka,ta", 20)));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "23 2 20 co,de,ka,ta\n25 1 30 4\n"
+        );
+        let elapsed: Vec<_> = runs
+            .history(25, 1)
+            .iter()
+            .map(|timing| timing.elapsed)
+            .collect();
+        assert_eq!(
+            elapsed,
+            [Duration::from_nanos(10), Duration::from_nanos(30)]
+        );
+        assert_eq!(runs.history(24, 1), []);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,179 @@
+//! `aoc new`: the crate of a new day, registered in the workspace and in the runner.
+
+use crate::{crate_dir, crate_name, YEAR};
+use std::{
+    fs,
+    io::{Error, ErrorKind, Result},
+    path::{Path, PathBuf},
+};
+
+const NEXT_YEAR: &str = "    // aoc new: next year\n";
+
+const CARGO_TOML: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+common = { path = "{root}common" }
+"#;
+
+const LIB_RS: &str = r#"use common::{OrExpected, OrReport, ParseError, Puzzle};
+
+pub struct Day{day};
+
+impl Puzzle for Day{day} {
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        input
+            .lines()
+            .filter_map(|line| {
+                line.parse()
+                    .or_expected(input, line, "a number")
+                    .or_report(errors)
+            })
+            .collect()
+    }
+
+    fn part1(_input: &Self::Input) -> i64 {
+        todo!()
+    }
+
+    fn part2(_input: &Self::Input) -> i64 {
+        todo!()
+    }
+}
+"#;
+
+const EXAMPLE_RS: &str = r#"use common::Puzzle;
+use {name}::Day{day};
+
+const EXAMPLE: &str = include_str!("../example.txt");
+
+#[test]
+#[ignore = "paste the example of the puzzle in example.txt and its answer here"]
+fn part1_example() {
+    let input = Day{day}::parse(EXAMPLE).unwrap();
+    assert_eq!(Day{day}::part1(&input).to_string(), "");
+}
+
+#[test]
+#[ignore = "paste the example of the puzzle in example.txt and its answer here"]
+fn part2_example() {
+    let input = Day{day}::parse(EXAMPLE).unwrap();
+    assert_eq!(Day{day}::part2(&input).to_string(), "");
+}
+"#;
+
+/// Creates the crate of `day` of `year` in `workspace`, adds it to the workspace members and to
+/// the `aoc` dependencies, and registers its parts in `aoc/src/days.rs`. Returns its directory.
+///
+/// Every day of [`YEAR`] already exists, so new days go to the other years.
+pub fn new_day(workspace: &Path, year: u16, day: u8) -> Result<PathBuf> {
+    if year == YEAR {
+        return Err(Error::new(
+            ErrorKind::Unsupported,
+            format!("The days of {YEAR} are all registered by hand"),
+        ));
+    }
+    let dir = crate_dir(year, day);
+    let name = crate_name(year, day);
+    let path = workspace.join(&dir);
+    if path.exists() {
+        return Err(Error::new(
+            ErrorKind::AlreadyExists,
+            format!("{} already exists", path.display()),
+        ));
+    }
+    let dir = dir
+        .to_str()
+        .expect("Crate directories are ASCII")
+        .replace('\\', "/");
+
+    // Every edit is prepared before anything is written, so a failure leaves the workspace as is
+    let members_path = workspace.join("Cargo.toml");
+    let members = add_member(&read(&members_path)?, &dir)
+        .ok_or_else(|| invalid(&members_path, "no `members = [...]` list"))?;
+    let dependencies_path = workspace.join("aoc").join("Cargo.toml");
+    let mut dependencies = read(&dependencies_path)?;
+    if !dependencies.ends_with('\n') {
+        dependencies.push('\n');
+    }
+    dependencies += &format!("{name} = {{ path = \"../{dir}\" }}\n");
+    let days_path = workspace.join("aoc").join("src").join("days.rs");
+    let days = register(&read(&days_path)?, year, day, &name)
+        .ok_or_else(|| invalid(&days_path, "no `aoc new` markers"))?;
+
+    let root = "../".repeat(dir.matches('/').count() + 1);
+    let fill = |template: &str| {
+        template
+            .replace("{name}", &name)
+            .replace("{day}", &day.to_string())
+            .replace("{root}", &root)
+    };
+    write(&path.join("Cargo.toml"), &fill(CARGO_TOML))?;
+    write(&path.join("src").join("lib.rs"), &fill(LIB_RS))?;
+    write(&path.join("tests").join("example.rs"), &fill(EXAMPLE_RS))?;
+    write(&path.join("example.txt"), "")?;
+    write(&members_path, &members)?;
+    write(&dependencies_path, &dependencies)?;
+    write(&days_path, &days)?;
+    Ok(path)
+}
+
+/// Appends `dir` at the end of the workspace members.
+fn add_member(manifest: &str, dir: &str) -> Option<String> {
+    let start = manifest.find("members = [")?;
+    let end = start + manifest[start..].find("\n]")? + 1;
+    Some(format!(
+        "{}    \"{dir}\",\n{}",
+        &manifest[..end],
+        &manifest[end..]
+    ))
+}
+
+/// Adds the parts of the day to the list of its year, creating the list when it is the first day
+/// of the year.
+fn register(days: &str, year: u16, day: u8, name: &str) -> Option<String> {
+    let next_day = format!("    // aoc new: next day of {year}\n");
+    let mut days = days.to_string();
+    if !days.contains(&next_day) {
+        let at = days.find(NEXT_YEAR)?;
+        days.insert_str(
+            at,
+            &format!("    Year {{\n        year: {year},\n        days: DAYS_{year},\n    }},\n"),
+        );
+        days += &format!("\npub static DAYS_{year}: &[Day] = &[\n{next_day}];\n");
+    }
+    let at = days.find(&next_day)?;
+    days.insert_str(
+        at,
+        &format!(
+            "    Day {{\n        number: {day},\n        parts: &[part1::<{name}::Day{day}>, part2::<{name}::Day{day}>],\n        check: check::<{name}::Day{day}>,\n    }},\n"
+        ),
+    );
+    Some(days)
+}
+
+fn read(path: &Path) -> Result<String> {
+    fs::read_to_string(path)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+    let with_path = |err: Error| Error::new(err.kind(), format!("{}: {err}", path.display()));
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory).map_err(with_path)?;
+    }
+    fs::write(path, content).map_err(with_path)
+}
+
+fn invalid(path: &Path, problem: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("{}: {problem}", path.display()),
+    )
+}
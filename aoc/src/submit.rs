@@ -0,0 +1,291 @@
+use crate::client::Client;
+use common::{OrExpected, ParseError};
+use std::{
+    fmt, fs,
+    io::{self, Error, ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+/// What the website said about an answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Rejected without saying which way it is off
+    Wrong,
+    /// Not judged because the last answer was sent too recently
+    Wait,
+}
+
+impl Verdict {
+    const ALL: [Verdict; 5] = [
+        Verdict::Correct,
+        Verdict::TooHigh,
+        Verdict::TooLow,
+        Verdict::Wrong,
+        Verdict::Wait,
+    ];
+
+    /// Reads the verdict out of the page answering a submission.
+    pub fn from_response(page: &str) -> Option<Verdict> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("too recently") {
+            Some(Verdict::Wait)
+        } else if page.contains("That's not the right answer") {
+            Some(if page.contains("too high") {
+                Verdict::TooHigh
+            } else if page.contains("too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else {
+            None
+        }
+    }
+
+    /// How the verdict is written in the history.
+    fn name(&self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too-high",
+            Verdict::TooLow => "too-low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "wait",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh => "too high",
+            Verdict::TooLow => "too low",
+            Verdict::Wrong => "wrong",
+            Verdict::Wait => "not judged, wait before submitting again",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for one year, kept in a file with one
+/// `<day> <part> <verdict> <answer>` line per submission.
+pub struct History {
+    path: PathBuf,
+    submissions: Vec<Submission>,
+}
+
+/// Where the history of `year` is kept inside `cache_dir`.
+pub fn history_path(cache_dir: &Path, year: u16) -> PathBuf {
+    cache_dir.join(year.to_string()).join("submissions.txt")
+}
+
+impl History {
+    /// An empty history when the file does not exist yet.
+    pub fn load(path: &Path) -> io::Result<History> {
+        let submissions = match fs::read_to_string(path) {
+            Ok(history) => parse(&history).map_err(|err| err.with_file(path))?,
+            Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(Error::new(err.kind(), format!("{}: {err}", path.display()))),
+        };
+        Ok(History {
+            path: path.to_path_buf(),
+            submissions,
+        })
+    }
+
+    pub fn submissions(&self) -> &[Submission] {
+        &self.submissions
+    }
+
+    /// Why `answer` should not be sent, if the history already tells it is wrong: the part is
+    /// solved, the answer was rejected before, or it is outside the bounds of earlier answers.
+    pub fn blocked(&self, day: u8, part: u8, answer: &str) -> Option<String> {
+        let mut submissions = self
+            .submissions
+            .iter()
+            .filter(|submission| submission.day == day && submission.part == part);
+        let value = answer.parse::<i128>().ok();
+        submissions.find_map(|submission| {
+            let known = submission.answer.parse::<i128>().ok();
+            match submission.verdict {
+                Verdict::Correct => Some(format!("already solved with {}", submission.answer)),
+                Verdict::Wait => None,
+                _ if submission.answer == answer => {
+                    Some(format!("{answer} was already {}", submission.verdict))
+                }
+                Verdict::TooHigh if value >= known && known.is_some() => Some(format!(
+                    "{answer} is not below {}, which is too high",
+                    submission.answer
+                )),
+                Verdict::TooLow if value <= known && value.is_some() => Some(format!(
+                    "{answer} is not above {}, which is too low",
+                    submission.answer
+                )),
+                _ => None,
+            }
+        })
+    }
+
+    /// Appends `submission` to the history file.
+    pub fn record(&mut self, submission: Submission) -> io::Result<()> {
+        let with_path =
+            |err: Error| Error::new(err.kind(), format!("{}: {err}", self.path.display()));
+        if let Some(directory) = self.path.parent() {
+            fs::create_dir_all(directory).map_err(with_path)?;
+        }
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(with_path)?;
+        writeln!(
+            file,
+            "{} {} {} {}",
+            submission.day,
+            submission.part,
+            submission.verdict.name(),
+            submission.answer
+        )
+        .map_err(with_path)?;
+        self.submissions.push(submission);
+        Ok(())
+    }
+}
+
+/// Sends `answer` unless the history blocks it, and records the verdict.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    year: u16,
+    day: u8,
+    part: u8,
+    answer: &str,
+) -> io::Result<Verdict> {
+    if let Some(reason) = history.blocked(day, part, answer) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("Not submitting day {day} part {part}: {reason}"),
+        ));
+    }
+    let page = client.answer(year, day, part, answer)?;
+    let verdict = Verdict::from_response(&page)
+        .ok_or_else(|| Error::other("The answer page has no verdict the client knows"))?;
+    history.record(Submission {
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+    Ok(verdict)
+}
+
+fn parse(history: &str) -> common::Result<Vec<Submission>> {
+    history
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.splitn(4, ' ');
+            let mut next_field = |expected: &str| {
+                fields
+                    .next()
+                    .or_expected(history, &line[line.len()..], expected)
+            };
+            let day = next_field("a day")?;
+            let part = next_field("a part")?;
+            let verdict = next_field("a verdict")?;
+            let answer = next_field("an answer")?;
+            Ok(Submission {
+                day: day.parse().or_expected(history, day, "a day")?,
+                part: part.parse().or_expected(history, part, "a part")?,
+                verdict: Verdict::ALL
+                    .into_iter()
+                    .find(|known| known.name() == verdict)
+                    .ok_or_else(|| ParseError::at(history, verdict, "a verdict"))?,
+                answer: answer.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(submissions: &[(u8, Verdict, &str)]) -> History {
+        History {
+            path: PathBuf::new(),
+            submissions: submissions
+                .iter()
+                .map(|(part, verdict, answer)| Submission {
+                    day: 1,
+                    part: *part,
+                    answer: answer.to_string(),
+                    verdict: *verdict,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn blocks_answers_known_to_be_wrong() {
+        let history = history(&[
+            (1, Verdict::TooHigh, "500"),
+            (1, Verdict::TooLow, "100"),
+            (1, Verdict::Wrong, "abc"),
+            (1, Verdict::Wait, "300"),
+            (2, Verdict::Correct, "42"),
+        ]);
+        assert!(history.blocked(1, 1, "500").is_some());
+        assert!(history.blocked(1, 1, "501").is_some());
+        assert!(history.blocked(1, 1, "100").is_some());
+        assert!(history.blocked(1, 1, "-3").is_some());
+        assert!(history.blocked(1, 1, "abc").is_some());
+        assert_eq!(history.blocked(1, 1, "300"), None);
+        assert_eq!(history.blocked(1, 1, "499"), None);
+        assert_eq!(history.blocked(1, 1, "a,b"), None);
+        assert!(history.blocked(1, 2, "43").is_some());
+        assert_eq!(history.blocked(2, 1, "500"), None);
+    }
+
+    #[test]
+    fn reads_verdicts_from_the_answer_page() {
+        let page = |text: &str| format!("<main><article><p>{text}</p></article></main>");
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's the right answer! You are one gold star closer."
+            )),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::from_response(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response(&page("You gave an answer too recently.")),
+            Some(Verdict::Wait)
+        );
+        assert_eq!(Verdict::from_response(&page("Hello")), None);
+    }
+
+    #[test]
+    fn history_file_round_trips() {
+        let submissions = parse("1 1 too-low 12\n\n25 1 correct 3,4\n").unwrap();
+        assert_eq!(submissions.len(), 2);
+        assert_eq!(submissions[0].verdict, Verdict::TooLow);
+        assert_eq!(submissions[1].answer, "3,4");
+        assert!(parse("1 1 maybe 12\n").is_err());
+    }
+}
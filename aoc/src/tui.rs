@@ -0,0 +1,378 @@
+//! `aoc tui`: a terminal dashboard of the days, with their last answers and times, that runs parts
+//! with a keypress and shows the logs and the final map of the days played on a map.
+
+use crate::{
+    answers, days, default_input, logger, maps,
+    params::Params,
+    run_all::{self, Job, Outcome, Status},
+    runs::{Run, Runs},
+};
+use ratatui::{
+    crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind},
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Cell, Paragraph, Row, Table, TableState},
+    DefaultTerminal, Frame,
+};
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+    sync::mpsc::{self, Receiver, Sender},
+    thread,
+    time::Duration,
+};
+
+/// What the side pane shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pane {
+    Log,
+    Map,
+}
+
+/// A part, or the map of the day as part `0`, solved in the background.
+struct Done {
+    day: u8,
+    part: u8,
+    outcome: Outcome,
+}
+
+pub struct App {
+    year: u16,
+    params: Params,
+    runs: Runs,
+    /// The registered answers of the real input of each part
+    expected: HashMap<(u8, u8), String>,
+    selected: usize,
+    pane: Pane,
+    /// Lines and columns scrolled, from the end of the log or from the top left of the map
+    scroll: (u16, u16),
+    log: Vec<String>,
+    maps: HashMap<u8, String>,
+    running: HashSet<(u8, u8)>,
+    done: (Sender<Done>, Receiver<Done>),
+    quit: bool,
+}
+
+impl App {
+    pub fn new(year: u16, params: Params, runs: Runs) -> io::Result<App> {
+        Ok(App {
+            year,
+            params,
+            runs,
+            expected: answers::expected(year)?,
+            selected: 0,
+            pane: Pane::Log,
+            scroll: (0, 0),
+            log: Vec::new(),
+            maps: HashMap::new(),
+            running: HashSet::new(),
+            done: mpsc::channel(),
+            quit: false,
+        })
+    }
+
+    fn days(&self) -> &'static [days::Day] {
+        days::YEARS
+            .iter()
+            .find(|year| year.year == self.year)
+            .map_or(&[], |year| year.days)
+    }
+
+    fn day(&self) -> Option<&'static days::Day> {
+        self.days().get(self.selected)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if key.kind != KeyEventKind::Press {
+            return;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.quit = true,
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Char('1') => self.start(&[1]),
+            KeyCode::Char('2') => self.start(&[2]),
+            KeyCode::Enter => self.start(&[1, 2, 0]),
+            KeyCode::Char('m') => {
+                self.start(&[0]);
+                self.pane = Pane::Map;
+            }
+            KeyCode::Tab => {
+                self.pane = match self.pane {
+                    Pane::Log => Pane::Map,
+                    Pane::Map => Pane::Log,
+                };
+                self.scroll = (0, 0);
+            }
+            KeyCode::PageDown => self.scroll.0 = self.scroll.0.saturating_add(10),
+            KeyCode::PageUp => self.scroll.0 = self.scroll.0.saturating_sub(10),
+            KeyCode::Right => self.scroll.1 = self.scroll.1.saturating_add(10),
+            KeyCode::Left => self.scroll.1 = self.scroll.1.saturating_sub(10),
+            KeyCode::Home => self.scroll = (0, 0),
+            _ => {}
+        }
+    }
+
+    fn select(&mut self, index: usize) {
+        let index = index.min(self.days().len().saturating_sub(1));
+        if index != self.selected {
+            self.selected = index;
+            self.scroll = (0, 0);
+        }
+    }
+
+    /// Solves `parts` of the selected day on its real input in the background, part `0` being the
+    /// map. Parts already running are skipped.
+    fn start(&mut self, parts: &[u8]) {
+        let Some(day) = self.day() else {
+            return;
+        };
+        let input = default_input(self.year, day.number);
+        let mut jobs = Vec::new();
+        for part in parts.iter().copied() {
+            let solver = match part {
+                0 => maps::get(day.number).filter(|_| self.year == crate::YEAR),
+                part => day.part(part),
+            };
+            let Some(solver) = solver else {
+                continue;
+            };
+            if !self.running.insert((day.number, part)) {
+                continue;
+            }
+            jobs.push(Job {
+                day: day.number,
+                part,
+                input: input.clone(),
+                expected: self.expected.get(&(day.number, part)).cloned(),
+                params: self.params.clone(),
+                solver,
+            });
+        }
+        let sender = self.done.0.clone();
+        thread::spawn(move || {
+            for job in jobs {
+                let (day, part) = (job.day, job.part);
+                let outcome = run_all::run(vec![job], 1).remove(0);
+                // The app is gone when the channel is closed, and the outcome with it
+                let _ = sender.send(Done { day, part, outcome });
+            }
+        });
+    }
+
+    /// Takes in the parts solved in the background and the records they logged.
+    pub fn update(&mut self) {
+        self.log.extend(logger::take());
+        while let Ok(Done { day, part, outcome }) = self.done.1.try_recv() {
+            self.running.remove(&(day, part));
+            let what = match part {
+                0 => format!("Day {day} map"),
+                part => format!("Day {day} part {part}"),
+            };
+            match (outcome.answer, outcome.status) {
+                (Some(map), _) if part == 0 => {
+                    self.log
+                        .push(format!("{what} drawn in {:.2?}", outcome.elapsed));
+                    self.maps.insert(day, map);
+                }
+                (Some(answer), status) => {
+                    let verdict = match status {
+                        Status::Wrong { expected } => format!(", expected {expected}"),
+                        _ => String::new(),
+                    };
+                    self.log.push(format!(
+                        "{what}: {answer} in {:.2?}{verdict}",
+                        outcome.elapsed
+                    ));
+                    let run = Run {
+                        answer,
+                        elapsed: outcome.elapsed,
+                    };
+                    if let Err(err) = self.runs.record(day, part, run) {
+                        self.log.push(format!("Cannot keep the run: {err}"));
+                    }
+                }
+                (None, Status::Failed(err)) => self.log.push(format!("{what} failed: {err}")),
+                (None, Status::Panicked(message)) => {
+                    self.log.push(format!("{what} panicked: {message}"))
+                }
+                (None, _) => {}
+            }
+        }
+    }
+
+    pub fn draw(&self, frame: &mut Frame) {
+        let [main, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [table, pane] =
+            Layout::horizontal([Constraint::Length(62), Constraint::Min(0)]).areas(main);
+        self.draw_days(frame, table);
+        self.draw_pane(frame, pane);
+        let help =
+            "↑↓ day  1/2 run part  Enter run all  m map  Tab log/map  PgUp/PgDn ←→ scroll  q quit";
+        frame.render_widget(Line::from(help).dark_gray(), footer);
+    }
+
+    fn draw_days(&self, frame: &mut Frame, area: Rect) {
+        let cells = |day: u8, part: u8| -> [Cell<'static>; 2] {
+            if self.running.contains(&(day, part)) {
+                return [Cell::from("running..."), Cell::from("")];
+            }
+            let Some(run) = self.runs.get(day, part) else {
+                return [Cell::from("-"), Cell::from("")];
+            };
+            let style = match self.expected.get(&(day, part)) {
+                Some(expected) if *expected == run.answer => Style::new().fg(Color::Green),
+                Some(_) => Style::new().fg(Color::Red),
+                None => Style::new(),
+            };
+            [
+                Cell::from(run.answer.clone()).style(style),
+                Cell::from(format!("{:.2?}", run.elapsed)),
+            ]
+        };
+        let rows = self.days().iter().map(|day| {
+            let [answer1, time1] = cells(day.number, 1);
+            let [answer2, time2] = cells(day.number, 2);
+            let map = if maps::DAYS.contains(&day.number) {
+                "▦"
+            } else {
+                ""
+            };
+            Row::new([
+                Cell::from(format!("{:>2} {map}", day.number)),
+                answer1,
+                time1,
+                answer2,
+                time2,
+            ])
+        });
+        let widths = [
+            Constraint::Length(5),
+            Constraint::Length(16),
+            Constraint::Length(10),
+            Constraint::Length(16),
+            Constraint::Length(10),
+        ];
+        let table = Table::new(rows, widths)
+            .header(Row::new(["Day", "Part 1", "Time", "Part 2", "Time"]).bold())
+            .row_highlight_style(Style::new().reversed())
+            .block(Block::bordered().title(format!(" Advent of Code {} ", self.year)));
+        let mut state = TableState::default().with_selected(Some(self.selected));
+        frame.render_stateful_widget(table, area, &mut state);
+    }
+
+    fn draw_pane(&self, frame: &mut Frame, area: Rect) {
+        let day = self.day().map_or(0, |day| day.number);
+        let (title, text, scroll) = match self.pane {
+            Pane::Log => {
+                // The end of the log stays in view, unless scrolled back
+                let height = area.height.saturating_sub(2) as usize;
+                let end = self.log.len().saturating_sub(self.scroll.0 as usize);
+                let start = end.saturating_sub(height);
+                (
+                    " Log ".to_string(),
+                    self.log[start..end].join("\n"),
+                    (0, self.scroll.1),
+                )
+            }
+            Pane::Map => {
+                let text = match self.maps.get(&day) {
+                    Some(map) => map.clone(),
+                    None if maps::DAYS.contains(&day) => "Press m to draw the map".to_string(),
+                    None => "This day has no map".to_string(),
+                };
+                (format!(" Day {day} map "), text, self.scroll)
+            }
+        };
+        let paragraph = Paragraph::new(text)
+            .scroll(scroll)
+            .block(Block::bordered().title(title));
+        frame.render_widget(paragraph, area);
+    }
+}
+
+/// Shows the dashboard until `q` is pressed. The records of the solvers go to the log pane, at
+/// least from the info level on.
+pub fn run(app: App) -> io::Result<()> {
+    if log::max_level() < log::LevelFilter::Info {
+        log::set_max_level(log::LevelFilter::Info);
+    }
+    logger::capture();
+    let terminal = ratatui::init();
+    let result = run_app(terminal, app);
+    ratatui::restore();
+    logger::release();
+    result
+}
+
+fn run_app(mut terminal: DefaultTerminal, mut app: App) -> io::Result<()> {
+    while !app.quit {
+        app.update();
+        terminal.draw(|frame| app.draw(frame))?;
+        if event::poll(Duration::from_millis(100))? {
+            if let Event::Key(key) = event::read()? {
+                app.handle_key(key);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runs::runs_path;
+    use ratatui::{backend::TestBackend, crossterm::event::KeyModifiers, Terminal};
+    use std::env;
+
+    fn app() -> App {
+        let dir = env::temp_dir().join(format!("aoc-tui-{}", std::process::id()));
+        App::new(
+            crate::YEAR,
+            Params::default(),
+            Runs::load(&runs_path(&dir, 2024)).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    fn screen(app: &App) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|frame| app.draw(frame)).unwrap();
+        let buffer = terminal.backend().buffer();
+        buffer
+            .content()
+            .chunks(buffer.area.width as usize)
+            .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn browses_days_and_panes() {
+        let mut app = app();
+        let start = screen(&app);
+        assert!(start.contains("Advent of Code 2024"), "{start}");
+        assert!(start.contains(" 1 "), "{start}");
+        assert!(start.contains(" Log "), "{start}");
+        for _ in 0..5 {
+            press(&mut app, KeyCode::Down);
+        }
+        press(&mut app, KeyCode::Tab);
+        let map = screen(&app);
+        assert!(map.contains("Day 6 map"), "{map}");
+        assert!(map.contains("Press m to draw the map"), "{map}");
+        press(&mut app, KeyCode::Up);
+        assert!(screen(&app).contains("This day has no map"));
+        for _ in 0..30 {
+            press(&mut app, KeyCode::Char('j'));
+        }
+        assert_eq!(app.day().map(|day| day.number), Some(25));
+        press(&mut app, KeyCode::Char('q'));
+        assert!(app.quit);
+    }
+}
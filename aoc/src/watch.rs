@@ -0,0 +1,194 @@
+//! `aoc watch`: rebuilds and reruns a day whenever its crate or its input changes.
+
+use serde::Deserialize;
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{Error, Result},
+    path::{Path, PathBuf},
+};
+
+/// The answer of each part of one run.
+pub type Answers = BTreeMap<u8, String>;
+
+#[derive(Deserialize)]
+struct Record {
+    part: u8,
+    answer: String,
+}
+
+/// Reads the answers out of the output of `aoc run --format json`, skipping anything else.
+pub fn answers(records: &str) -> Answers {
+    records
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Record>(line).ok())
+        .map(|record| (record.part, record.answer))
+        .collect()
+}
+
+/// One line per part, telling how its answer changed since `previous`.
+pub fn diff(previous: &Answers, current: &Answers) -> Vec<String> {
+    let mut parts: Vec<&u8> = previous.keys().chain(current.keys()).collect();
+    parts.sort();
+    parts.dedup();
+    parts
+        .into_iter()
+        .map(|part| match (previous.get(part), current.get(part)) {
+            (Some(before), Some(now)) if before == now => format!("part {part}: {now}"),
+            (Some(before), Some(now)) => format!("part {part}: {before} -> {now}"),
+            (None, Some(now)) => format!("part {part}: {now} (new)"),
+            (Some(before), None) => format!("part {part}: {before} -> no answer"),
+            (None, None) => unreachable!("Every part comes from one of the runs"),
+        })
+        .collect()
+}
+
+/// `path` from the root, since the reruns of `aoc run` start in the workspace and a relative path
+/// would no longer point at the same file.
+pub fn absolute(path: &Path) -> Result<PathBuf> {
+    fs::canonicalize(path)
+        .map_err(|err| Error::new(err.kind(), format!("{}: {err}", path.display())))
+}
+
+#[cfg(target_os = "linux")]
+pub use inotify_watch::watch;
+
+#[cfg(target_os = "linux")]
+mod inotify_watch {
+    use super::{absolute, answers, diff, Answers};
+    use crate::{day_dir, workspace_dir};
+    use inotify::{Inotify, WatchMask};
+    use std::{
+        env,
+        ffi::OsString,
+        fs,
+        io::{ErrorKind, Result},
+        path::{Path, PathBuf},
+        process::{Command, Stdio},
+        thread,
+        time::Duration,
+    };
+
+    /// Runs `aoc run` on `day` through `cargo run`, so the day is rebuilt first, then again every
+    /// time a file of its crate or of the directory of `input` changes. `args` are passed on to
+    /// `aoc run`, with paths already [`absolute`]. Only returns on errors of inotify, or when
+    /// `input` does not exist.
+    pub fn watch(year: u16, day: u8, input: Option<&Path>, args: &[OsString]) -> Result<()> {
+        let input = input.map(absolute).transpose()?;
+        let input = input.as_deref();
+        let mut inotify = Inotify::init()?;
+        let mask =
+            WatchMask::CLOSE_WRITE | WatchMask::CREATE | WatchMask::DELETE | WatchMask::MOVED_TO;
+        let mut directories = Vec::new();
+        subdirectories(&day_dir(year, day), &mut directories)?;
+        let default_input = crate::default_input(year, day);
+        let input_dir = input.unwrap_or(&default_input).parent();
+        directories.extend(input_dir.map(Path::to_path_buf));
+        for directory in directories.iter().filter(|directory| directory.is_dir()) {
+            inotify.watches().add(directory, mask)?;
+        }
+
+        let mut previous = Answers::new();
+        let mut buffer = [0; 4096];
+        loop {
+            if let Some(current) = run(year, day, input, args) {
+                for line in diff(&previous, &current) {
+                    println!("Day {day} {line}");
+                }
+                previous = current;
+            }
+            println!("Watching day {day} for changes...");
+            inotify.read_events_blocking(&mut buffer)?;
+            // Editors and `cargo fmt` touch several files at once, so wait for them to settle
+            loop {
+                thread::sleep(Duration::from_millis(200));
+                match inotify.read_events(&mut buffer) {
+                    Ok(_) => continue,
+                    Err(err) if err.kind() == ErrorKind::WouldBlock => break,
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    /// The answers, or `None` when the day did not build or failed, which cargo and the runner
+    /// already reported on stderr.
+    fn run(year: u16, day: u8, input: Option<&Path>, args: &[OsString]) -> Option<Answers> {
+        let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+        let mut command = Command::new(cargo);
+        command
+            .current_dir(workspace_dir())
+            .args(["run", "--release", "--quiet", "-p", "aoc", "--", "run"])
+            .args(["--format", "json", "--year", &year.to_string()])
+            .args(["--day", &day.to_string()])
+            .args(args)
+            .stderr(Stdio::inherit());
+        if let Some(input) = input {
+            command.arg("--input").arg(input);
+        }
+        let output = match command.output() {
+            Ok(output) => output,
+            Err(err) => {
+                eprintln!("Error: cannot run cargo: {err}");
+                return None;
+            }
+        };
+        output
+            .status
+            .success()
+            .then(|| answers(&String::from_utf8_lossy(&output.stdout)))
+    }
+
+    /// `directory` and every directory inside it.
+    fn subdirectories(directory: &Path, all: &mut Vec<PathBuf>) -> Result<()> {
+        all.push(directory.to_path_buf());
+        for entry in fs::read_dir(directory)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() {
+                subdirectories(&entry.path(), all)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_answers_from_json_records() {
+        let records = concat!(
+            r#"{"day":1,"part":1,"input":"day1/example.txt","answer":"11","elapsed":1e-6}"#,
+            "\n",
+            r#"{"day":1,"part":2,"input":"day1/example.txt","answer":"31","elapsed":1e-6}"#,
+            "\nnot a record\n"
+        );
+        let answers = answers(records);
+        assert_eq!(answers, Answers::from([(1, "11".into()), (2, "31".into())]));
+    }
+
+    #[test]
+    fn tells_what_changed() {
+        let previous = Answers::from([(1, "11".into()), (2, "31".into())]);
+        let current = Answers::from([(1, "11".into()), (2, "32".into())]);
+        assert_eq!(
+            diff(&previous, &current),
+            vec!["part 1: 11".to_string(), "part 2: 31 -> 32".to_string()]
+        );
+        assert_eq!(
+            diff(&Answers::new(), &previous),
+            vec![
+                "part 1: 11 (new)".to_string(),
+                "part 2: 31 (new)".to_string()
+            ]
+        );
+        assert_eq!(
+            diff(&previous, &Answers::from([(1, "11".into())])),
+            vec![
+                "part 1: 11".to_string(),
+                "part 2: 31 -> no answer".to_string()
+            ]
+        );
+    }
+}
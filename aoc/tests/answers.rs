@@ -0,0 +1,77 @@
+use aoc::{answers, days, input_path, params::Params};
+use std::fs;
+
+/// Runs every registered answer of `day` whose input file is present.
+fn check_day(day: u8) {
+    let answers = answers::load().expect("The answer registry should be readable");
+    let mut failures = Vec::new();
+    for answer in answers.iter().filter(|answer| answer.day == day) {
+        let path = input_path(day, &answer.input);
+        let Ok(input) = fs::read_to_string(&path) else {
+            eprintln!(
+                "Skipping day {day} part {}: {} is missing",
+                answer.part,
+                path.display()
+            );
+            continue;
+        };
+        let solver = days::get(day)
+            .and_then(|solvers| solvers.part(answer.part))
+            .unwrap_or_else(|| panic!("Day {day} has no part {}", answer.part));
+        let params = answer.params.as_ref().map(|params| input_path(day, params));
+        let params = Params::load(params.as_deref(), &[])
+            .unwrap_or_else(|err| panic!("Day {day} has invalid parameters: {err}"));
+        match solver(&input, &params) {
+            Ok(found) if found == answer.expected => {}
+            Ok(found) => failures.push(format!(
+                "part {} on {}: expected {}, found {found}",
+                answer.part, answer.input, answer.expected
+            )),
+            Err(err) => failures.push(format!(
+                "part {} on {}: {}",
+                answer.part,
+                answer.input,
+                err.with_file(&path)
+            )),
+        }
+    }
+    assert!(
+        failures.is_empty(),
+        "Day {day} regressed:\n{}",
+        failures.join("\n")
+    );
+}
+
+macro_rules! regressions {
+    ($($test:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $test() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+regressions! {
+    day1 => 1, day2 => 2, day3 => 3, day4 => 4, day5 => 5,
+    day6 => 6, day7 => 7, day8 => 8, day9 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+}
+
+#[test]
+fn registry_is_valid() {
+    let answers = answers::load().expect("The answer registry should be readable");
+    for answer in answers {
+        assert!(
+            days::get(answer.day)
+                .and_then(|solvers| solvers.part(answer.part))
+                .is_some(),
+            "Day {} has no part {}",
+            answer.day,
+            answer.part
+        );
+    }
+}
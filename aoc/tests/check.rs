@@ -0,0 +1,215 @@
+use aoc::{days, input_path, params::Params};
+use common::ParseError;
+use std::fs;
+
+fn check(day: u8, input: &str) -> Vec<ParseError> {
+    (days::get(day).expect("Every day is registered").check)(input, &Params::default())
+}
+
+#[test]
+fn examples_have_no_problems() {
+    for day in days::DAYS.iter() {
+        let Ok(input) = fs::read_to_string(input_path(day.number, "example.txt")) else {
+            continue;
+        };
+        assert_eq!(
+            (day.check)(&input, &Params::default()),
+            vec![],
+            "day {}",
+            day.number
+        );
+    }
+}
+
+#[test]
+fn reports_every_ragged_row() {
+    assert_eq!(
+        check(4, "XMAS\nXMA\nXMASX\n"),
+        vec![
+            ParseError::new(2, 4, "4 tiles in the row", "end of line"),
+            ParseError::new(3, 5, "the row to end after 4 tiles", "`X`"),
+        ]
+    );
+    assert_eq!(
+        check(8, "..a.\n.#..\n...\n"),
+        vec![
+            ParseError::new(2, 2, "a map tile", "`#..`"),
+            ParseError::new(3, 4, "4 tiles in the row", "end of line"),
+        ]
+    );
+}
+
+#[test]
+fn reports_every_broken_arcade() {
+    let input = "Button A: X+94, Y+34\nButton B: X+22, Y+67\nPrize: X=8400, Y=5400\n\
+                 Button A: X+1, Y+2\n\nButton A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=3\n";
+    assert_eq!(
+        check(13, input),
+        vec![
+            ParseError::new(
+                4,
+                1,
+                "an empty line after the prize",
+                "`Button A: X+1, Y+2`"
+            ),
+            ParseError::new(5, 1, "a `Button B` line", "end of line"),
+            ParseError::new(8, 8, "two coordinates separated by `,`", "`X=3`"),
+        ]
+    );
+}
+
+#[test]
+fn reports_robots_outside_the_map() {
+    assert_eq!(
+        check(14, "p=0,0 v=1,1\np=101,5 v=1,1\np=3,-1 v=1,1\n"),
+        vec![
+            ParseError::new(2, 3, "a position between 0 and 100", "`101`"),
+            ParseError::new(3, 5, "a position between 0 and 102", "`-1`"),
+        ]
+    );
+}
+
+#[test]
+fn reports_every_broken_schematic() {
+    let input = "#####\n.###.\n..#..\n.....\n.....\n.....\n.....\n\n\
+                 .....\n#....\n#x..#\n#####\n\n\
+                 #####\n#####\n.....\n.....\n.....\n.....\n#####\n";
+    assert_eq!(
+        check(25, input),
+        vec![
+            ParseError::new(11, 2, "`#` or `.`", "`x..#`"),
+            ParseError::new(12, 6, "a schematic seven rows tall", "end of line"),
+            ParseError::new(
+                14,
+                1,
+                "`#####` on the top row of a lock or the bottom row of a key",
+                "`#####`"
+            ),
+        ]
+    );
+}
+
+#[test]
+fn reports_every_unknown_tile() {
+    assert_eq!(
+        check(10, "0123\n1a34\n"),
+        vec![ParseError::new(2, 2, "a map tile", "`a34`")]
+    );
+    assert_eq!(
+        check(12, "AAB\nA.B\n"),
+        vec![ParseError::new(2, 2, "a map tile", "`.B`")]
+    );
+}
+
+#[test]
+fn reports_every_missing_or_repeated_marker() {
+    assert_eq!(
+        check(6, "..#.\n.^x.\n^...\n"),
+        vec![
+            ParseError::new(2, 3, "a map tile", "`x.`"),
+            ParseError::new(3, 1, "a single `^` in the map", "`^...`"),
+        ]
+    );
+    assert_eq!(
+        check(16, "#####\n#S.E#\n#S.x#\n"),
+        vec![
+            ParseError::new(3, 4, "a map tile", "`x#`"),
+            ParseError::new(3, 2, "a single `S` in the map", "`S.x#`"),
+        ]
+    );
+    assert_eq!(
+        check(20, "#####\n#..E#\n#####\n"),
+        vec![ParseError::new(
+            4,
+            1,
+            "a start `S` in the racetrack",
+            "end of input"
+        )]
+    );
+}
+
+#[test]
+fn reports_every_problem_of_a_warehouse() {
+    assert_eq!(
+        check(15, "#####\n#@.O.\n#.@##\n####\n\n<>x\n"),
+        vec![
+            ParseError::new(4, 5, "5 tiles in the row", "end of line"),
+            ParseError::new(2, 5, "a wall `#` around the warehouse", "`.`"),
+            ParseError::new(3, 3, "a single `@` in the map", "`@##`"),
+            ParseError::new(6, 3, "one of `^>v<`", "`x`"),
+        ]
+    );
+}
+
+#[test]
+fn reports_every_broken_byte() {
+    assert_eq!(
+        check(18, "1,2\n3\n5,71\n"),
+        vec![
+            ParseError::new(2, 1, "two coordinates separated by `,`", "`3`"),
+            ParseError::new(3, 3, "a coordinate between 0 and 70", "`71`"),
+        ]
+    );
+}
+
+#[test]
+fn reports_every_broken_line() {
+    assert_eq!(
+        check(2, "1 2 x\n4 5\ny 3\n"),
+        vec![
+            ParseError::new(1, 5, "a number", "`x`"),
+            ParseError::new(3, 1, "a number", "`y`"),
+        ]
+    );
+    assert_eq!(
+        check(
+            24,
+            "x00: 1\nx01: 2\n\nx00 AND x01 -> z00\nx00 NAND y02 -> z01\n"
+        ),
+        vec![
+            ParseError::new(2, 6, "`0` or `1`", "`2`"),
+            ParseError::new(5, 5, "`AND`, `OR` or `XOR`", "`NAND`"),
+            ParseError::new(4, 9, "a known wire", "`x01`"),
+        ]
+    );
+    // Where each problem is, for the days reporting one problem per line
+    for (day, input, problems) in [
+        (1, "3 4\n4 x\n5\n", [(2, 3), (3, 2)]),
+        (5, "1|2\n3|x\n\n1,2\n3,y\n", [(2, 3), (5, 3)]),
+        (7, "190: 10 19\n3267 81 40\n83: x\n", [(2, 1), (3, 5)]),
+        (9, "12x45y\n", [(1, 3), (1, 6)]),
+        (11, "125 -5 x", [(1, 5), (1, 8)]),
+        (
+            14,
+            "p=0,4 v=3,-3\nq=1,1 v=1,1\np=1,1 v=x,1\n",
+            [(2, 1), (3, 9)],
+        ),
+        (
+            17,
+            "Register A: x\nRegister B: 0\nRegister C: y\n\nProgram: 0,1\n",
+            [(1, 13), (3, 13)],
+        ),
+        (19, "r, wr, x\n\nbrwrr\nbyq\n", [(1, 8), (4, 1)]),
+        (21, "029A\n9x0A\nA\n", [(2, 2), (3, 1)]),
+        (22, "1\nx\n-3\n", [(2, 1), (3, 1)]),
+        (23, "kh-tc\nqp\nde=cg\n", [(2, 1), (3, 1)]),
+    ] {
+        let found: Vec<(usize, usize)> = check(day, input)
+            .into_iter()
+            .map(|problem| (problem.line, problem.column))
+            .collect();
+        assert_eq!(found, problems, "day {day}");
+    }
+}
+
+#[test]
+fn checks_bytes_against_the_configured_memory_space() {
+    let mut params = Params::default();
+    params.day18.grid_size = 6;
+    let check = days::get(18).unwrap().check;
+    assert_eq!(check("1,6\n7,2\n", &Params::default()), vec![]);
+    assert_eq!(
+        check("1,6\n7,2\n", &params),
+        vec![ParseError::new(2, 1, "a coordinate between 0 and 6", "`7`")]
+    );
+}
@@ -0,0 +1,33 @@
+use aoc::{
+    differential::{self, Outcome},
+    params::Params,
+};
+
+/// Both algorithms of `day` on a few generated inputs of `size`.
+fn check_day(day: u8, seeds: u64, size: usize) {
+    let pair = differential::get(day).expect("The day has two algorithms");
+    match differential::check(pair, 0..seeds, size, &Params::default()) {
+        Outcome::Agree => {}
+        Outcome::NoAnswers => panic!("Day {day} {} had no answer to compare", pair.name),
+        Outcome::Disagree(disagreement) => panic!(
+            "Day {day} {} disagree with seed {}, {}:\n{}",
+            pair.name, disagreement.seed, disagreement.reason, disagreement.input
+        ),
+    }
+}
+
+#[test]
+fn day11() {
+    check_day(11, 20, 10);
+}
+
+#[test]
+fn day18() {
+    // The brute force searches a path after every byte
+    check_day(18, 10, 20);
+}
+
+#[test]
+fn day19() {
+    check_day(19, 20, 10);
+}
@@ -0,0 +1,46 @@
+mod stub;
+
+use aoc::{client::Client, fetch};
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+/// An empty cache directory for one test.
+fn cache_dir(test: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-fetch-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn downloads_into_the_year_directory() {
+    let stub = stub::serve(vec![(200, "3   4\n4   3\n")]);
+    let cache = cache_dir("download");
+    let path = fetch::fetch(&Client::new(&stub.url, "secret"), &cache, 2024, 1).unwrap();
+    assert_eq!(path, cache.join("2024").join("day1.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), "3   4\n4   3\n");
+    let requests = stub.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].line, "GET /2024/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn never_downloads_a_cached_input_again() {
+    let stub = stub::serve(vec![(200, "first"), (200, "second")]);
+    let cache = cache_dir("cached");
+    let client = Client::new(&stub.url, "secret");
+    fetch::fetch(&client, &cache, 2024, 7).unwrap();
+    let err = fetch::fetch(&client, &cache, 2024, 7).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    assert_eq!(stub.requests().len(), 1);
+    let cached = fs::read_to_string(fetch::cached_input(&cache, 2024, 7)).unwrap();
+    assert_eq!(cached, "first");
+}
+
+#[test]
+fn caches_nothing_on_http_errors() {
+    let stub = stub::serve(vec![(400, "Please log in")]);
+    let cache = cache_dir("error");
+    let err = fetch::fetch(&Client::new(&stub.url, "expired"), &cache, 2024, 3).unwrap_err();
+    assert!(err.to_string().contains("400"), "{err}");
+    assert!(!fetch::cached_input(&cache, 2024, 3).exists());
+}
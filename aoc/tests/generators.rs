@@ -0,0 +1,89 @@
+use aoc::{days, params::Params};
+use std::collections::HashSet;
+
+/// Parts whose solver does not handle every valid input yet.
+const KNOWN_FAILURES: &[(u8, u8)] = &[
+    // The search of register A gives up on most programs that are not the author's.
+    (17, 2),
+];
+
+const SIZE: usize = 10;
+
+/// The puzzle constants of the generated inputs of `SIZE`.
+fn params() -> Params {
+    Params::default().generated(SIZE)
+}
+
+/// Checks and solves generated inputs of `day` with a few seeds, at the smallest size and at `SIZE`.
+fn check_day(day: u8) {
+    let solvers = days::get(day).expect("Every day has solvers");
+    for size in [1, SIZE] {
+        let params = Params::default().generated(size);
+        for seed in 0..5 {
+            let input = generators::generate(day, seed, size).expect("Every day has a generator");
+            let problems = (solvers.check)(&input, &params);
+            assert_eq!(
+                problems,
+                vec![],
+                "Day {day} with seed {seed}, size {size}\n{input}"
+            );
+            for (part, solver) in (1..).zip(solvers.parts) {
+                if KNOWN_FAILURES.contains(&(day, part)) {
+                    continue;
+                }
+                eprintln!("Day {day} part {part} with seed {seed}, size {size}");
+                if let Err(err) = solver(&input, &params) {
+                    panic!(
+                        "Day {day} part {part} rejected the input of seed {seed}, size {size}: \
+                         {err}\n{input}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn known_answers() {
+    for day in [18, 24] {
+        let solvers = days::get(day).expect("Every day has solvers");
+        for seed in 0..5 {
+            let (input, part, answer) =
+                generators::generate_with_answer(day, seed, SIZE).expect("The answer is known");
+            let solver = solvers.part(part).expect("The part has a solver");
+            assert_eq!(
+                solver(&input, &params()),
+                Ok(answer),
+                "Day {day} part {part} with seed {seed}\n{input}"
+            );
+        }
+    }
+}
+
+#[test]
+fn four_pairs_of_wires_are_swapped_at_the_minimum_size() {
+    for seed in 0..20 {
+        let (_, _, answer) = generators::generate_with_answer(24, seed, 0).unwrap();
+        let wires: HashSet<&str> = answer.split(',').collect();
+        assert_eq!(wires.len(), 8, "{answer} with seed {seed}");
+    }
+}
+
+macro_rules! generated {
+    ($($test:ident => $day:literal),* $(,)?) => {
+        $(
+            #[test]
+            fn $test() {
+                check_day($day);
+            }
+        )*
+    };
+}
+
+generated! {
+    day1 => 1, day2 => 2, day3 => 3, day4 => 4, day5 => 5,
+    day6 => 6, day7 => 7, day8 => 8, day9 => 9, day10 => 10,
+    day11 => 11, day12 => 12, day13 => 13, day14 => 14, day15 => 15,
+    day16 => 16, day17 => 17, day18 => 18, day19 => 19, day20 => 20,
+    day21 => 21, day22 => 22, day23 => 23, day24 => 24, day25 => 25,
+}
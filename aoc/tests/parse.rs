@@ -0,0 +1,82 @@
+use aoc::{answers, days, input_path, params::Params};
+use common::{ParseError, Puzzle};
+use std::fs;
+
+#[test]
+fn rejects_a_warehouse_that_is_not_walled_in() {
+    assert_eq!(
+        day15::Day15::parse("@.\n\n<").err(),
+        Some(ParseError::new(
+            1,
+            1,
+            "a wall `#` around the warehouse",
+            "`@.`"
+        ))
+    );
+    assert_eq!(
+        day15::Day15::parse("####\n#@.#\n##.#\n\n<").err(),
+        Some(ParseError::new(
+            3,
+            3,
+            "a wall `#` around the warehouse",
+            "`.#`"
+        ))
+    );
+}
+
+#[test]
+fn rejects_an_input_without_secret_numbers() {
+    assert_eq!(
+        day22::Day22::parse("\n\n"),
+        Err(ParseError::new(3, 1, "a secret number", "end of input"))
+    );
+}
+
+#[test]
+fn rejects_a_negative_stone() {
+    assert_eq!(
+        day11::Day11::parse("125 -5"),
+        Err(ParseError::new(1, 5, "a stone number", "`-5`"))
+    );
+}
+
+#[test]
+fn rejects_door_codes_that_are_not_three_digits_and_a() {
+    assert_eq!(
+        day21::Day21::parse("029A\n99999999999A\n").err(),
+        Some(ParseError::new(2, 4, "`A`", "`99999999A`"))
+    );
+    assert_eq!(
+        day21::Day21::parse("29A\n").err(),
+        Some(ParseError::new(1, 3, "a digit", "`A`"))
+    );
+    assert_eq!(
+        day21::Day21::parse("029A0\n").err(),
+        Some(ParseError::new(1, 5, "the code to end after `A`", "`0`"))
+    );
+}
+
+#[test]
+fn reads_crlf_line_endings() {
+    assert_eq!(
+        day18::Day18::parse("5,4\r\n4,2\r\n"),
+        day18::Day18::parse("5,4\n4,2\n")
+    );
+    let answers = answers::load().expect("The answer registry should be readable");
+    for answer in answers
+        .iter()
+        .filter(|answer| answer.input.starts_with("example"))
+    {
+        let (day, part) = (answer.day, answer.part);
+        let input = fs::read_to_string(input_path(day, &answer.input)).unwrap();
+        let solver = days::get(day).and_then(|day| day.part(part)).unwrap();
+        let params = answer.params.as_ref().map(|params| input_path(day, params));
+        let params = Params::load(params.as_deref(), &[]).unwrap();
+        assert_eq!(
+            solver(&input.replace('\n', "\r\n"), &params),
+            Ok(answer.expected.clone()),
+            "day {day} part {part} on {}",
+            answer.input
+        );
+    }
+}
@@ -0,0 +1,52 @@
+mod snapshot;
+
+use aoc::{input_path, maps, params::Params};
+use common::Puzzle;
+use snapshot::assert_snapshot;
+use std::fs;
+
+fn example(day: u8, file: &str) -> String {
+    fs::read_to_string(input_path(day, file)).unwrap()
+}
+
+#[test]
+fn day14_robots() {
+    let robots = day14::Day14::parse(&example(14, "example.txt")).unwrap();
+    assert_snapshot("day14_start", &day14::draw_map(&robots, (11, 7), 0));
+    assert_snapshot("day14_after_100", &day14::draw_map(&robots, (11, 7), 100));
+}
+
+#[test]
+fn day15_warehouses() {
+    for (file, name) in [("example.txt", "day15"), ("example_2.txt", "day15_2")] {
+        let warehouse = day15::Day15::parse(&example(15, file)).unwrap();
+        assert_snapshot(name, &day15::draw_after_moves(&warehouse));
+        assert_snapshot(
+            &format!("{name}_wide"),
+            &day15::draw_wide_after_moves(&warehouse),
+        );
+    }
+}
+
+#[test]
+fn final_maps() {
+    for day in maps::DAYS {
+        let (file, params) = match day {
+            16 => ("example_1.txt", None),
+            14 | 18 | 20 => ("example.txt", Some("example.toml")),
+            _ => ("example.txt", None),
+        };
+        let params = params.map_or_else(Params::default, |params| {
+            Params::load(Some(&input_path(day, params)), &[]).unwrap()
+        });
+        let render = maps::get(day).unwrap();
+        let map = render(&example(day, file), &params).unwrap();
+        assert_snapshot(&format!("map_day{day}"), &map);
+    }
+}
+
+#[test]
+fn day16_walled_off_destination_draws_no_path() {
+    let maze = day16::Day16::parse(&example(16, "walled_off.txt")).unwrap();
+    assert_eq!(day16::draw_best_paths(&maze), "#####\n#.#.#\n#####\n");
+}
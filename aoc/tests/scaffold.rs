@@ -0,0 +1,57 @@
+use aoc::{scaffold, workspace_dir};
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+/// A copy of the files of the workspace that `aoc new` edits.
+fn workspace(test: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-new-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("aoc/src")).unwrap();
+    for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+        fs::copy(workspace_dir().join(file), dir.join(file)).unwrap();
+    }
+    dir
+}
+
+#[test]
+fn creates_and_registers_days_of_a_new_year() {
+    let workspace = workspace("register");
+    let day3 = scaffold::new_day(&workspace, 2025, 3).unwrap();
+    scaffold::new_day(&workspace, 2025, 4).unwrap();
+    assert_eq!(day3, workspace.join("2025/day3"));
+    for file in [
+        "Cargo.toml",
+        "src/lib.rs",
+        "tests/example.rs",
+        "example.txt",
+    ] {
+        assert!(day3.join(file).exists(), "{file} is missing");
+    }
+    let manifest = fs::read_to_string(day3.join("Cargo.toml")).unwrap();
+    assert!(manifest.contains("name = \"day3_2025\""));
+    assert!(manifest.contains("path = \"../../common\""));
+
+    let members = fs::read_to_string(workspace.join("Cargo.toml")).unwrap();
+    assert!(members.contains("    \"2025/day3\",\n    \"2025/day4\",\n]"));
+    let dependencies = fs::read_to_string(workspace.join("aoc/Cargo.toml")).unwrap();
+    assert!(dependencies.contains("day4_2025 = { path = \"../2025/day4\" }\n"));
+    let days = fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap();
+    assert_eq!(days.matches("days: DAYS_2025,").count(), 1);
+    assert!(days.contains("parts: &[part1::<day3_2025::Day3>, part2::<day3_2025::Day3>],"));
+    assert!(days.contains("parts: &[part1::<day4_2025::Day4>, part2::<day4_2025::Day4>],"));
+    assert!(days.contains("check: check::<day3_2025::Day3>,"));
+}
+
+#[test]
+fn never_overwrites_a_day() {
+    let workspace = workspace("existing");
+    scaffold::new_day(&workspace, 2023, 1).unwrap();
+    let days = fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap();
+    let err = scaffold::new_day(&workspace, 2023, 1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+    assert_eq!(
+        fs::read_to_string(workspace.join("aoc/src/days.rs")).unwrap(),
+        days
+    );
+    let err = scaffold::new_day(&workspace, aoc::YEAR, 1).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Unsupported);
+}
@@ -0,0 +1,47 @@
+//! Expected renders, kept as text files in `tests/snapshots`. Run the tests with
+//! `UPDATE_SNAPSHOTS=1` to write them from the current renders, then review the diff.
+
+use std::{env, fs, path::PathBuf};
+
+/// Fails when `render` differs from the snapshot `name`, listing the lines that changed.
+pub fn assert_snapshot(name: &str, render: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.txt"));
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, render).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path).unwrap_or_else(|err| {
+        panic!(
+            "{}: {err}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display()
+        )
+    });
+    if expected == render {
+        return;
+    }
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let lines: Vec<&str> = render.lines().collect();
+    let changes: Vec<String> = (0..expected_lines.len().max(lines.len()))
+        .filter(|i| expected_lines.get(*i) != lines.get(*i))
+        .map(|i| {
+            let line =
+                |line: Option<&&str>| line.map_or("nothing".to_string(), |l| format!("`{l}`"));
+            format!(
+                "line {}: expected {}, found {}",
+                i + 1,
+                line(expected_lines.get(i)),
+                line(lines.get(i))
+            )
+        })
+        .collect();
+    panic!(
+        "The render differs from {}, run with UPDATE_SNAPSHOTS=1 if the change is wanted:\n{}\n{}",
+        path.display(),
+        changes.join("\n"),
+        render
+    );
+}
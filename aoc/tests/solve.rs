@@ -0,0 +1,124 @@
+//! Inputs that follow the grammar of their day but used to make the solvers panic or never stop.
+
+use aoc::{
+    days::{self, SolveError},
+    input_path,
+    params::Params,
+};
+use common::Puzzle;
+use std::fs;
+
+#[test]
+fn day6_guard_that_never_leaves_has_no_answer() {
+    let boxed_in = day6::Day6::parse(".#.\n#^#\n.#.\n").unwrap();
+    assert_eq!(day6::Day6::part1(&boxed_in), None);
+    assert_eq!(day6::Day6::part2(&boxed_in), None);
+    let in_a_loop = day6::Day6::parse(".#..\n...#\n#^..\n..#.\n").unwrap();
+    assert_eq!(day6::Day6::part1(&in_a_loop), None);
+    assert_eq!(day6::Day6::part2(&in_a_loop), None);
+}
+
+#[test]
+fn day9_disk_without_blocks() {
+    for input in ["", "0\n", "00\n"] {
+        let disk = day9::Day9::parse(input).unwrap();
+        assert_eq!(day9::Day9::part1(&disk), 0);
+        assert_eq!(day9::Day9::part2(&disk), 0);
+    }
+}
+
+#[test]
+fn day11_stone_number_overflow_has_no_answer() {
+    let stones = day11::Day11::parse("1111111111111111111").unwrap();
+    assert_eq!(day11::Day11::part1(&stones), None);
+    assert_eq!(day11::Day11::part2(&stones), None);
+}
+
+#[test]
+fn day13_buttons_moving_in_the_same_direction() {
+    let arcades = day13::Day13::parse(
+        "Button A: X+1, Y+2\nButton B: X+2, Y+4\nPrize: X=3, Y=6\n\n\
+         Button A: X+3, Y+3\nButton B: X+2, Y+2\nPrize: X=7, Y=7\n\n\
+         Button A: X+1, Y+1\nButton B: X+2, Y+2\nPrize: X=3, Y=4\n",
+    )
+    .unwrap();
+    assert_eq!(day13::Day13::part1(&arcades), Some(4 + 5));
+}
+
+#[test]
+fn day13_button_without_a_horizontal_move() {
+    let arcades =
+        day13::Day13::parse("Button A: X+0, Y+5\nButton B: X+3, Y+0\nPrize: X=6, Y=10\n").unwrap();
+    assert_eq!(day13::Day13::part1(&arcades), Some(8));
+}
+
+#[test]
+fn day14_velocity_larger_than_the_map() {
+    let robots = day14::Day14::parse("p=0,0 v=-300,1\n").unwrap();
+    assert_eq!(day14::Day14::part1(&robots), 0);
+    assert_eq!(
+        day14::draw_map(&robots, (11, 7), 1),
+        day14::draw_map(&day14::Day14::parse("p=8,1 v=0,0\n").unwrap(), (11, 7), 0)
+    );
+    assert!(day14::Day14::part2(&robots) > 0);
+}
+
+#[test]
+fn day16_walled_off_destination_has_no_answer() {
+    let input = fs::read_to_string(input_path(16, "walled_off.txt")).unwrap();
+    let maze = day16::Day16::parse(&input).unwrap();
+    assert_eq!(day16::Day16::part1(&maze), None);
+    assert_eq!(day16::Day16::part2(&maze), None);
+    let solver = days::get(16).and_then(|day| day.part(1)).unwrap();
+    let err = solver(&input, &Params::default())
+        .unwrap_err()
+        .with_file("input.txt");
+    assert_eq!(
+        err,
+        SolveError::NoAnswer {
+            file: Some("input.txt".into())
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "input.txt: the part has no answer for this input"
+    );
+}
+
+#[test]
+fn day17_program_that_never_stops_has_no_answer() {
+    let looping =
+        day17::Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n")
+            .unwrap();
+    assert_eq!(day17::Day17::part1(&looping), None);
+    let never_halting =
+        day17::Day17::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,0,3,0\n")
+            .unwrap();
+    assert_eq!(day17::Day17::part2(&never_halting), None);
+}
+
+#[test]
+fn day17_program_without_output_has_no_answer() {
+    let silent =
+        day17::Day17::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,0\n")
+            .unwrap();
+    assert_eq!(day17::Day17::part2(&silent), None);
+}
+
+#[test]
+fn day20_cheat_between_sides_reached_at_the_same_time() {
+    let racetrack = day20::Day20::parse("#######\n#E.S..#\n#.###.#\n#..#..#\n#######\n").unwrap();
+    assert_eq!(day20::count_short_cheats(&racetrack, 0), 3);
+    assert_eq!(day20::count_short_cheats(&racetrack, 1), 2);
+    assert_eq!(day20::Day20::part1(&racetrack), 0);
+}
+
+#[test]
+fn day24_examples_that_are_not_adders_have_no_answer() {
+    for example in ["example_1.txt", "example_2.txt"] {
+        let input = fs::read_to_string(input_path(24, example)).unwrap();
+        let device = day24::Day24::parse(&input).unwrap();
+        assert_eq!(day24::Day24::part2(&device), None, "{example}");
+    }
+    assert_eq!(day24::Day24::part2(&Default::default()), None);
+}
@@ -0,0 +1,81 @@
+//! A local HTTP server answering a fixed list of responses, one per connection.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+};
+
+/// A request as the stub received it.
+#[derive(Debug)]
+pub struct Request {
+    /// e.g. `GET /2024/day/1/input`
+    pub line: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+pub struct Stub {
+    pub url: String,
+    requests: Receiver<Request>,
+}
+
+impl Stub {
+    /// Every request served so far.
+    pub fn requests(&self) -> Vec<Request> {
+        self.requests.try_iter().collect()
+    }
+}
+
+/// Serves `responses`, as status and body pairs, in order and then stops listening.
+pub fn serve(responses: Vec<(u16, &'static str)>) -> Stub {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let (sender, requests) = mpsc::channel();
+    thread::spawn(move || {
+        for (status, body) in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let mut headers = Vec::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                let Some((name, value)) = header.trim_end().split_once(':') else {
+                    break;
+                };
+                headers.push((name.to_string(), value.trim().to_string()));
+            }
+            let mut request = Request {
+                line: line.trim_end().rsplit_once(' ').unwrap().0.to_string(),
+                headers,
+                body: String::new(),
+            };
+            let length = request
+                .header("Content-Length")
+                .map_or(0, |length| length.parse().unwrap());
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request.body = String::from_utf8(content).unwrap();
+            sender.send(request).unwrap();
+            write!(
+                stream,
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+        }
+    });
+    Stub { url, requests }
+}
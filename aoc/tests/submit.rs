@@ -0,0 +1,58 @@
+mod stub;
+
+use aoc::{
+    client::Client,
+    submit::{self, History, Verdict},
+};
+use std::{env, fs, io::ErrorKind, path::PathBuf};
+
+/// A history file that does not exist yet, for one test.
+fn history_path(test: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc-submit-{test}-{}", std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    submit::history_path(&dir, 2024)
+}
+
+const TOO_HIGH: &str =
+    "<article><p>That's not the right answer; your answer is too high.</p></article>";
+const WAIT: &str = "<article><p>You gave an answer too recently; you have to wait.</p></article>";
+const CORRECT: &str =
+    "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+
+#[test]
+fn posts_the_answer_and_records_the_verdict() {
+    let stub = stub::serve(vec![(200, CORRECT)]);
+    let path = history_path("correct");
+    let mut history = History::load(&path).unwrap();
+    let client = Client::new(&stub.url, "secret");
+    let verdict = submit::submit(&client, &mut history, 2024, 5, 2, "123").unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    let requests = stub.requests();
+    assert_eq!(requests[0].line, "POST /2024/day/5/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=2&answer=123");
+    assert_eq!(fs::read_to_string(&path).unwrap(), "5 2 correct 123\n");
+}
+
+#[test]
+fn blocks_answers_ruled_out_by_earlier_verdicts() {
+    let stub = stub::serve(vec![(200, TOO_HIGH), (200, WAIT), (200, CORRECT)]);
+    let path = history_path("blocked");
+    let client = Client::new(&stub.url, "secret");
+    let mut history = History::load(&path).unwrap();
+    let verdict = submit::submit(&client, &mut history, 2024, 1, 1, "900").unwrap();
+    assert_eq!(verdict, Verdict::TooHigh);
+
+    // Read back from the file, as a later run would
+    let mut history = History::load(&path).unwrap();
+    for answer in ["900", "1000"] {
+        let err = submit::submit(&client, &mut history, 2024, 1, 1, answer).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+    let verdict = submit::submit(&client, &mut history, 2024, 1, 1, "800").unwrap();
+    assert_eq!(verdict, Verdict::Wait);
+    let verdict = submit::submit(&client, &mut history, 2024, 1, 1, "800").unwrap();
+    assert_eq!(verdict, Verdict::Correct);
+    assert_eq!(stub.requests().len(), 3);
+    assert_eq!(History::load(&path).unwrap().submissions().len(), 3);
+}
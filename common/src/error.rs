@@ -0,0 +1,186 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    io,
+    path::PathBuf,
+};
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Where and why a puzzle input could not be parsed. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(
+        line: usize,
+        column: usize,
+        expected: impl Into<String>,
+        found: impl Into<String>,
+    ) -> Self {
+        Self {
+            file: None,
+            line,
+            column,
+            expected: expected.into(),
+            found: found.into(),
+        }
+    }
+
+    /// Points at the start of `fragment`, which has to be a slice of `input`.
+    pub fn at(input: &str, fragment: &str, expected: impl Into<String>) -> Self {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len() && input.is_char_boundary(*offset))
+            .unwrap_or(input.len());
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+        let found = match fragment.lines().next() {
+            Some(found) if !found.is_empty() => format!("`{}`", shorten(found)),
+            _ if offset == input.len() => "end of input".to_string(),
+            _ => "end of line".to_string(),
+        };
+        Self::new(line, column, expected, found)
+    }
+
+    /// Points at the end of `input`, for sections that are missing altogether.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at(input, &input[input.len()..], expected)
+    }
+
+    pub fn with_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.file = Some(file.into());
+        self
+    }
+}
+
+fn shorten(found: &str) -> String {
+    const MAX_CHARS: usize = 32;
+    match found.char_indices().nth(MAX_CHARS) {
+        Some((end, _)) => format!("{}...", &found[..end]),
+        None => found.to_string(),
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, found {}",
+            self.line, self.column, self.expected, self.found
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    fn from(err: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, err)
+    }
+}
+
+/// Turns a missing value or a failed conversion into a [`ParseError`] pointing at `fragment`.
+pub trait OrExpected<T> {
+    fn or_expected(self, input: &str, fragment: &str, expected: &str) -> Result<T>;
+}
+
+impl<T> OrExpected<T> for Option<T> {
+    fn or_expected(self, input: &str, fragment: &str, expected: &str) -> Result<T> {
+        self.ok_or_else(|| ParseError::at(input, fragment, expected))
+    }
+}
+
+impl<T, E> OrExpected<T> for std::result::Result<T, E> {
+    fn or_expected(self, input: &str, fragment: &str, expected: &str) -> Result<T> {
+        self.map_err(|_| ParseError::at(input, fragment, expected))
+    }
+}
+
+/// Keeps parsing after a problem, for parsers that report every problem of the input rather than
+/// the first one.
+pub trait OrReport<T> {
+    /// The value, or `None` once the error is added to `errors`.
+    fn or_report(self, errors: &mut Vec<ParseError>) -> Option<T>;
+}
+
+impl<T> OrReport<T> for Result<T> {
+    fn or_report(self, errors: &mut Vec<ParseError>) -> Option<T> {
+        self.map_err(|err| errors.push(err)).ok()
+    }
+}
+
+/// What `read` reads, or the first problem it adds to its errors. `read` keeps going after a
+/// problem, and what it returns is only complete without any.
+pub fn first_error<T>(read: impl FnOnce(&mut Vec<ParseError>) -> T) -> Result<T> {
+    let mut errors = Vec::new();
+    let value = read(&mut errors);
+    match errors.into_iter().next() {
+        Some(err) => Err(err),
+        None => Ok(value),
+    }
+}
+
+/// Every problem `read` adds to its errors, where [`first_error`] stops at the first.
+pub fn all_errors<T>(read: impl FnOnce(&mut Vec<ParseError>) -> T) -> Vec<ParseError> {
+    let mut errors = Vec::new();
+    read(&mut errors);
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3   4\n4   x3\n";
+
+    #[test]
+    fn points_at_the_fragment() {
+        let line = INPUT.lines().nth(1).unwrap();
+        let number = &line[4..];
+        let err = number.parse::<i64>().or_expected(INPUT, number, "a number");
+        assert_eq!(err, Err(ParseError::new(2, 5, "a number", "`x3`")));
+        assert_eq!(
+            err.unwrap_err().with_file("day1/input.txt").to_string(),
+            "day1/input.txt:2:5: expected a number, found `x3`"
+        );
+    }
+
+    #[test]
+    fn reports_and_keeps_going() {
+        let (input, mut errors) = (INPUT, Vec::new());
+        let numbers: Vec<i64> = input
+            .split_whitespace()
+            .filter_map(|n| {
+                n.parse()
+                    .or_expected(input, n, "a number")
+                    .or_report(&mut errors)
+            })
+            .collect();
+        assert_eq!(numbers, vec![3, 4, 4]);
+        assert_eq!(errors, vec![ParseError::new(2, 5, "a number", "`x3`")]);
+    }
+
+    #[test]
+    fn points_at_the_end_of_the_input() {
+        assert_eq!(
+            ParseError::end(INPUT, "a third line"),
+            ParseError::new(3, 1, "a third line", "end of input")
+        );
+        let line = INPUT.lines().next().unwrap();
+        assert_eq!(
+            None::<()>.or_expected(INPUT, &line[line.len()..], "a column"),
+            Err(ParseError::new(1, 6, "a column", "end of line"))
+        );
+    }
+}
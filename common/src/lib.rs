@@ -0,0 +1,9 @@
+mod error;
+mod list;
+mod puzzle;
+mod sections;
+
+pub use error::{all_errors, first_error, OrExpected, OrReport, ParseError, Result};
+pub use list::{Iter, List};
+pub use puzzle::{Answer, Puzzle};
+pub use sections::{sections, split_at_empty_line};
@@ -0,0 +1,190 @@
+use std::{fmt, rc::Rc};
+
+/// An immutable singly linked list where every `prepend` shares the existing nodes, so keeping
+/// many versions of a growing path is cheap. Cloning only copies a pointer.
+///
+/// Based on <https://rust-unofficial.github.io/too-many-lists/third-final.html>.
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Rc<Node<T>>>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: None, len: 0 }
+    }
+
+    /// A new list with `elem` in front of this one, which is left untouched.
+    pub fn prepend(&self, elem: T) -> List<T> {
+        List {
+            head: Some(Rc::new(Node {
+                elem,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    /// The list without its head. Empty when this one is.
+    pub fn tail(&self) -> List<T> {
+        List {
+            head: self.head.as_ref().and_then(|node| node.next.clone()),
+            len: self.len.saturating_sub(1),
+        }
+    }
+
+    pub fn head(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.head.is_none()
+    }
+
+    /// From the head, i.e. from the last prepended element.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+        }
+    }
+
+    /// From the first prepended element to the head.
+    pub fn iter_rev(&self) -> impl Iterator<Item = &T> {
+        self.iter().collect::<Vec<_>>().into_iter().rev()
+    }
+
+    /// The elements from the head.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Clone for List<T> {
+    fn clone(&self) -> Self {
+        List {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+/// The first element of the iterator becomes the head.
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let elems: Vec<T> = iter.into_iter().collect();
+        elems
+            .into_iter()
+            .rev()
+            .fold(List::new(), |list, elem| list.prepend(elem))
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+/// Drops the nodes one by one, so that long lists do not overflow the stack. Stops at the first
+/// node still shared with another list.
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        let mut head = self.head.take();
+        while let Some(node) = head {
+            if let Ok(mut node) = Rc::try_unwrap(node) {
+                head = node.next.take();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prepends_and_iterates_both_ways() {
+        let list = List::new().prepend(1).prepend(2).prepend(3);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.head(), Some(&3));
+        assert_eq!(list.to_vec(), vec![3, 2, 1]);
+        assert_eq!(list.iter_rev().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.tail().to_vec(), vec![2, 1]);
+        assert_eq!(list.tail().tail().tail().tail().len(), 0);
+        assert_eq!(list, [3, 2, 1].into_iter().collect());
+    }
+
+    #[test]
+    fn versions_share_their_nodes() {
+        let base: List<i32> = [2, 1].into_iter().collect();
+        let left = base.prepend(3);
+        let right = base.prepend(4);
+        let shared = |list: &List<i32>| list.tail().head.clone().unwrap();
+        assert!(Rc::ptr_eq(&shared(&left), &shared(&right)));
+        assert!(Rc::ptr_eq(&shared(&left), base.head.as_ref().unwrap()));
+        drop(base);
+        drop(left);
+        assert_eq!(right.to_vec(), vec![4, 2, 1]);
+        assert_eq!(right.clone(), right);
+    }
+
+    #[test]
+    fn drops_long_lists() {
+        let list: List<u32> = (0..1_000_000).collect();
+        assert_eq!(list.len(), 1_000_000);
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{all_errors, first_error, ParseError, Result};
+
+/// One day of the advent, solved on an input held in memory and parsed once for both parts.
+pub trait Puzzle {
+    /// The puzzle input once parsed.
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// Reads the input, adding every problem to `errors` rather than stopping at the first. The
+    /// input is only complete without any.
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        first_error(|errors| Self::read(input, errors))
+    }
+
+    /// Every problem of the input, where `parse` stops at the first one.
+    fn check(input: &str) -> Vec<ParseError> {
+        all_errors(|errors| Self::read(input, errors))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+/// What a part answers, as the text to submit. `None` when the input has no answer, e.g. a maze
+/// whose exit is cut off.
+pub trait Answer {
+    fn answer(&self) -> Option<String>;
+}
+
+macro_rules! answers {
+    ($($answer:ty),*) => {
+        $(
+            impl Answer for $answer {
+                fn answer(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+answers!(i32, i64, u32, u64, usize, String, &str);
+
+impl<T: Answer> Answer for Option<T> {
+    fn answer(&self) -> Option<String> {
+        self.as_ref()?.answer()
+    }
+}
@@ -0,0 +1,50 @@
+/// The lines before the first empty line, with their line breaks, and the ones after it. Like
+/// [`str::lines`], a line may end with `\r\n`, so inputs saved on Windows split the same.
+pub fn split_at_empty_line(input: &str) -> Option<(&str, &str)> {
+    let mut start = 0;
+    for line in input.split_inclusive('\n') {
+        if line == "\n" || line == "\r\n" {
+            return Some((&input[..start], &input[start + line.len()..]));
+        }
+        start += line.len();
+    }
+    None
+}
+
+/// The parts of the input separated by empty lines, skipping the empty ones.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut rest = Some(input);
+    std::iter::from_fn(move || {
+        let input = rest?;
+        let (section, after) = split_at_empty_line(input).unzip();
+        rest = after;
+        Some(section.unwrap_or(input))
+    })
+    .filter(|section| !section.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_at_the_first_empty_line() {
+        assert_eq!(
+            split_at_empty_line("a\nb\n\nc\n\nd\n"),
+            Some(("a\nb\n", "c\n\nd\n"))
+        );
+        assert_eq!(
+            split_at_empty_line("a\r\nb\r\n\r\nc\r\n"),
+            Some(("a\r\nb\r\n", "c\r\n"))
+        );
+        assert_eq!(split_at_empty_line("a\nb\n"), None);
+    }
+
+    #[test]
+    fn skips_empty_sections() {
+        assert_eq!(
+            sections("a\n\n\nb\r\nc\r\n\r\n").collect::<Vec<_>>(),
+            vec!["a\n", "b\r\nc\r\n"]
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use common::{first_error, OrExpected, OrReport, ParseError, Puzzle, Result};
+use std::collections::HashMap;
+use std::ops::AddAssign;
+
+pub struct Day1;
+
+impl Puzzle for Day1 {
+    /// The left list of locations in the first column and the right one in the second.
+    type Input = LocationLists;
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_two_lists(input, errors)
+    }
+
+    fn part1(lists: &Self::Input) -> Option<i64> {
+        lists.total_distance(0, 1)
+    }
+
+    fn part2(lists: &Self::Input) -> Option<i64> {
+        lists.similarity_score(0, 1)
+    }
+}
+
+fn read_two_lists(input: &str, errors: &mut Vec<ParseError>) -> LocationLists {
+    let lists = LocationLists::read(input, errors);
+    if errors.is_empty() && lists.columns() < 2 {
+        errors.push(ParseError::at(input, input, "two lists of locations"));
+    }
+    lists
+}
+
+/// Lists of location ids written side by side, one column per list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocationLists {
+    lists: Vec<Vec<i64>>,
+}
+
+impl LocationLists {
+    /// Reads the numbers of each line, separated by any whitespace, in a single pass. The first
+    /// line gives the number of columns and every other line must have as many.
+    pub fn parse(input: &str) -> Result<Self> {
+        first_error(|errors| Self::read(input, errors))
+    }
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self {
+        let mut lists: Vec<Vec<i64>> = Vec::new();
+        for line in input.lines().filter(|line| !line.trim().is_empty()) {
+            let first_line = lists.is_empty();
+            let mut column = 0;
+            for location in line.split_whitespace() {
+                if first_line {
+                    lists.push(Vec::new());
+                }
+                let columns = lists.len();
+                let Some(list) = lists.get_mut(column) else {
+                    errors.push(ParseError::at(
+                        input,
+                        location,
+                        format!("{columns} numbers"),
+                    ));
+                    break;
+                };
+                let location = location
+                    .parse::<i64>()
+                    .or_expected(input, location, "a number");
+                list.extend(location.or_report(errors));
+                column += 1;
+            }
+            if column < lists.len() {
+                let expected = format!("{} numbers", lists.len());
+                errors.push(ParseError::at(input, &line[line.len()..], expected));
+            }
+        }
+        LocationLists { lists }
+    }
+
+    pub fn columns(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// The list in `column`, `None` past the last one.
+    pub fn column(&self, column: usize) -> Option<&[i64]> {
+        self.lists.get(column).map(Vec::as_slice)
+    }
+
+    /// Sum of the distances between the smallest location of both lists, then the second smallest,
+    /// and so on. `None` when either column does not exist.
+    pub fn total_distance(&self, left: usize, right: usize) -> Option<i64> {
+        let mut left = self.column(left)?.to_vec();
+        let mut right = self.column(right)?.to_vec();
+        left.sort_unstable();
+        right.sort_unstable();
+        Some(
+            left.into_iter()
+                .zip(right)
+                .map(|(element1, element2)| (element2 - element1).abs())
+                .sum(),
+        )
+    }
+
+    /// Sum of each location of the left list times how often it appears in the right one. `None`
+    /// when either column does not exist.
+    pub fn similarity_score(&self, left: usize, right: usize) -> Option<i64> {
+        let mut occurences_right: HashMap<i64, u64> = HashMap::new();
+        for element_right in self.column(right)? {
+            occurences_right
+                .entry(*element_right)
+                .or_default()
+                .add_assign(1);
+        }
+        Some(
+            self.column(left)?
+                .iter()
+                .map(|element1| {
+                    element1 * (occurences_right.get(element1).cloned().unwrap_or(0)) as i64
+                })
+                .sum(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_any_whitespace_and_number_of_columns() {
+        let lists = LocationLists::parse("3\t4  7\n 4 \t3\t1\n").unwrap();
+        assert_eq!(lists.columns(), 3);
+        assert_eq!(lists.column(0), Some(&[3, 4][..]));
+        assert_eq!(lists.column(2), Some(&[7, 1][..]));
+        assert_eq!(lists.total_distance(0, 2), Some(5));
+        assert_eq!(lists.similarity_score(0, 1), Some(7));
+    }
+
+    #[test]
+    fn rejects_a_ragged_row() {
+        assert_eq!(
+            LocationLists::parse("3 4\n4\n"),
+            Err(ParseError::new(2, 2, "2 numbers", "end of line"))
+        );
+        assert_eq!(
+            LocationLists::parse("3 4\n4 3 5\n"),
+            Err(ParseError::new(2, 5, "2 numbers", "`5`"))
+        );
+    }
+
+    #[test]
+    fn has_no_answer_past_the_last_column() {
+        let lists = LocationLists::parse("3 4\n4 3\n").unwrap();
+        assert_eq!(lists.column(2), None);
+        assert_eq!(lists.total_distance(0, 2), None);
+        assert_eq!(lists.similarity_score(2, 1), None);
+    }
+}
@@ -0,0 +1,72 @@
+use common::{ParseError, Puzzle};
+use grid::{Grid, Point};
+use std::collections::HashSet;
+
+pub struct Day10;
+
+impl Puzzle for Day10 {
+    /// Heights of the map, `-1` being impassable
+    type Input = Grid<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        Grid::read(input, height, errors)
+    }
+
+    fn part1(data: &Self::Input) -> i32 {
+        let mut trail_heads = 0;
+        for (position, height) in data.iter() {
+            if *height == 0 {
+                trail_heads += calc_trail(position, data).len() as i32;
+            }
+        }
+        trail_heads
+    }
+
+    fn part2(data: &Self::Input) -> i32 {
+        let mut trail_heads = 0;
+        for (position, height) in data.iter() {
+            if *height == 0 {
+                trail_heads += calc_trail2(position, data);
+            }
+        }
+        trail_heads
+    }
+}
+
+fn calc_trail(position: Point, data: &Grid<i32>) -> HashSet<Point> {
+    let current = data[position];
+    if current == 9 {
+        return HashSet::from([position]);
+    }
+    let mut finished_trails = HashSet::new();
+    for neighbour in data.neighbours4(position) {
+        if data[neighbour] == current + 1 {
+            finished_trails.extend(calc_trail(neighbour, data));
+        }
+    }
+    finished_trails
+}
+
+fn calc_trail2(position: Point, data: &Grid<i32>) -> i32 {
+    let current = data[position];
+    if current == 9 {
+        return 1;
+    }
+    let mut finished_trails = 0;
+    for neighbour in data.neighbours4(position) {
+        if data[neighbour] == current + 1 {
+            finished_trails += calc_trail2(neighbour, data);
+        }
+    }
+    finished_trails
+}
+
+fn height(c: char) -> Option<i32> {
+    if c == '.' {
+        Some(-1)
+    } else {
+        c.to_digit(10).map(|height| height as i32)
+    }
+}
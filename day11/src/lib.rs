@@ -0,0 +1,121 @@
+use common::{OrExpected, OrReport, ParseError, Puzzle};
+use std::collections::HashMap;
+
+pub struct Day11;
+
+/// Blinks of the first part
+pub const PART1_BLINKS: usize = 25;
+/// Blinks of the second part
+pub const PART2_BLINKS: usize = 75;
+
+impl Puzzle for Day11 {
+    /// The numbers engraved on the stones
+    type Input = Vec<u64>;
+    /// No answer when a stone number no longer fits in a `u64`
+    type Answer1 = Option<usize>;
+    type Answer2 = Option<usize>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_stones(input, errors)
+    }
+
+    fn part1(stones: &Self::Input) -> Option<usize> {
+        solve(stones, PART1_BLINKS)
+    }
+
+    fn part2(stones: &Self::Input) -> Option<usize> {
+        solve_part_two(stones, PART2_BLINKS)
+    }
+}
+
+/// Simulates every blink, one stone at a time. `None` when a stone number overflows.
+pub fn solve(stones: &[u64], n_iterations: usize) -> Option<usize> {
+    let mut stones = stones.to_vec();
+
+    for blink in 0..n_iterations {
+        log::debug!("Iteration {blink} with {} stones", stones.len());
+        let mut i = 0;
+        let limit = stones.len();
+        while i < limit {
+            let number = stones[i];
+            if number == 0 {
+                stones[i] = 1;
+                i += 1;
+                continue;
+            }
+            let n_digits = number.ilog10() + 1;
+            if n_digits.is_multiple_of(2) {
+                let base = 10u64.pow(n_digits / 2);
+                let right_part = number % base;
+                let left_part = number / base;
+                stones[i] = left_part;
+                // stones.insert(i + 1, right_part);
+                stones.push(right_part);
+                i += 1;
+                continue;
+            }
+            stones[i] = number.checked_mul(2024)?;
+            i += 1;
+        }
+    }
+
+    Some(stones.len())
+}
+
+/// Counts the stones each stone turns into, remembering the count for every stone and blink.
+/// `None` when a stone number or the count overflows.
+pub fn solve_part_two(stones: &[u64], n_iterations: usize) -> Option<usize> {
+    let mut result = 0;
+    let mut memoization = HashMap::new();
+    for stone in stones {
+        let count = solve_with_memoization(&mut memoization, *stone, n_iterations)?;
+        result = count.checked_add(result)?;
+    }
+    log::info!("Solved with {} different memoizations", memoization.len());
+    Some(result)
+}
+
+fn solve_with_memoization(
+    memoization: &mut HashMap<(u64, usize), usize>,
+    stone: u64,
+    n_iterations: usize,
+) -> Option<usize> {
+    if n_iterations == 0 {
+        return Some(1);
+    }
+    if let Some(n) = memoization.get(&(stone, n_iterations)) {
+        return Some(*n);
+    }
+    let result = {
+        if stone == 0 {
+            solve_with_memoization(memoization, 1, n_iterations - 1)?
+        } else {
+            let n_digits = stone.ilog10() + 1;
+            if n_digits.is_multiple_of(2) {
+                let base = 10u64.pow(n_digits / 2);
+                let right_part = stone % base;
+                let left_part = stone / base;
+                let left = solve_with_memoization(memoization, left_part, n_iterations - 1)?;
+                let right = solve_with_memoization(memoization, right_part, n_iterations - 1)?;
+                left.checked_add(right)?
+            } else {
+                solve_with_memoization(memoization, stone.checked_mul(2024)?, n_iterations - 1)?
+            }
+        }
+    };
+    memoization.insert((stone, n_iterations), result);
+    Some(result)
+}
+
+/// Stone numbers separated by spaces
+fn read_stones(input: &str, errors: &mut Vec<ParseError>) -> Vec<u64> {
+    input
+        .trim()
+        .split(' ')
+        .filter_map(|word| {
+            word.parse()
+                .or_expected(input, word, "a stone number")
+                .or_report(errors)
+        })
+        .collect()
+}
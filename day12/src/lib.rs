@@ -0,0 +1,139 @@
+use common::{ParseError, Puzzle};
+use grid::{Direction, Grid, Point};
+
+pub struct Day12;
+
+impl Puzzle for Day12 {
+    /// The plant growing on each plot
+    type Input = Grid<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        Grid::read(input, plant, errors)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        part_1(map)
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        part_2(map)
+    }
+}
+
+/// Fence price using the perimeter of each region
+fn part_1(map: &Grid<char>) -> usize {
+    let regions = map_into_regions(map.clone());
+    let mut total_cost = 0;
+    for region in regions {
+        let mut perimeter = 0;
+        for plant in &region.plants {
+            for direction in Direction::ALL {
+                let neighbour = map.step(*plant, direction).map(|neighbour| map[neighbour]);
+                if neighbour != Some(region.plant_type) {
+                    perimeter += 1;
+                }
+            }
+        }
+        total_cost += perimeter * region.plants.len();
+    }
+    total_cost
+}
+
+/// Fence price using the number of sides of each region, which is also its number of corners
+fn part_2(map: &Grid<char>) -> usize {
+    let regions = map_into_regions(map.clone());
+    let mut total_cost = 0;
+    for region in regions {
+        let mut corners = 0;
+        for plant in &region.plants {
+            let neighborhood = create_neighborhood_check(*plant, map);
+            let horizontal_budies = neighborhood[1][0] as usize + neighborhood[1][2] as usize;
+            let vertical_budies = neighborhood[0][1] as usize + neighborhood[2][1] as usize;
+            let outer_corners = match (horizontal_budies, vertical_budies) {
+                (2, _) | (_, 2) => 0,
+                (1, 1) => 1,
+                (1, 0) | (0, 1) => 2,
+                (0, 0) => 4,
+                _ => 0,
+            };
+            let mut inner_corners = 0;
+            if neighborhood[0][1] && neighborhood[1][0] && !neighborhood[0][0] {
+                inner_corners += 1;
+            }
+            if neighborhood[0][1] && neighborhood[1][2] && !neighborhood[0][2] {
+                inner_corners += 1;
+            }
+            if neighborhood[2][1] && neighborhood[1][0] && !neighborhood[2][0] {
+                inner_corners += 1;
+            }
+            if neighborhood[2][1] && neighborhood[1][2] && !neighborhood[2][2] {
+                inner_corners += 1;
+            }
+            corners += inner_corners + outer_corners;
+        }
+        total_cost += corners * region.plants.len();
+    }
+    total_cost
+}
+
+/// The index of the region of each plot, regions being numbered from the top left corner
+pub fn regions(map: &Grid<char>) -> Grid<usize> {
+    let mut regions = Grid::new(map.width(), map.height(), 0);
+    for (index, region) in map_into_regions(map.clone()).into_iter().enumerate() {
+        for plant in region.plants {
+            regions[plant] = index;
+        }
+    }
+    regions
+}
+
+/// Plants are uppercase letters, `.` marking the plots already put in a region
+fn plant(c: char) -> Option<char> {
+    c.is_ascii_uppercase().then_some(c)
+}
+
+fn map_into_regions(mut map: Grid<char>) -> Vec<Region> {
+    let mut result = Vec::new();
+    while let Some(position) = map.position(|plant| *plant != '.') {
+        let plant_type = map[position];
+        let plants = extract_region_from_map(position, &mut map);
+        result.push(Region { plant_type, plants })
+    }
+    result
+}
+
+fn extract_region_from_map(position: Point, map: &mut Grid<char>) -> Vec<Point> {
+    let plant_type = map[position];
+    map[position] = '.';
+    let mut result = vec![position];
+    let neighbours: Vec<Point> = map.neighbours4(position).collect();
+    for neighbour in neighbours {
+        if map[neighbour] == plant_type {
+            result.extend(extract_region_from_map(neighbour, map));
+        }
+    }
+    result
+}
+
+/// The 3x3 square around `position`, marking which tiles have the same plant
+fn create_neighborhood_check(position: Point, map: &Grid<char>) -> [[bool; 3]; 3] {
+    let plant = map[position];
+    let mut neighborhood = [[false; 3]; 3];
+    for (dy, row) in (-1..=1).zip(neighborhood.iter_mut()) {
+        for (dx, same_plant) in (-1..=1).zip(row.iter_mut()) {
+            *same_plant = position
+                .offset(dx, dy)
+                .and_then(|neighbour| map.get(neighbour))
+                == Some(&plant);
+        }
+    }
+    neighborhood
+}
+
+#[derive(Debug)]
+struct Region {
+    plant_type: char,
+    plants: Vec<Point>,
+}
@@ -0,0 +1,251 @@
+use common::{OrExpected, ParseError, Puzzle, Result};
+
+pub struct Day13;
+
+impl Puzzle for Day13 {
+    type Input = Vec<Arcade>;
+    /// No answer when the tokens do not fit in an `i64`
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_arcades(input, errors)
+    }
+
+    fn part1(arcades: &Self::Input) -> Option<i64> {
+        count_tokens(arcades, 0)
+    }
+
+    fn part2(arcades: &Self::Input) -> Option<i64> {
+        count_tokens(arcades, 10000000000000)
+    }
+}
+
+/// Tokens to win every prize that can be won, once `prize_offset` is added to both coordinates
+fn count_tokens(arcades: &[Arcade], prize_offset: i64) -> Option<i64> {
+    let mut tokens: i128 = 0;
+    for arcade in arcades {
+        let Some(solution) = solve(
+            i128::from(arcade.prize.0) + i128::from(prize_offset),
+            i128::from(arcade.prize.1) + i128::from(prize_offset),
+            arcade.a.x_move.into(),
+            arcade.a.y_move.into(),
+            arcade.b.x_move.into(),
+            arcade.b.y_move.into(),
+        ) else {
+            continue;
+        };
+        tokens = tokens.checked_add(solution.0.checked_mul(3)?.checked_add(solution.1)?)?;
+    }
+    tokens.try_into().ok()
+}
+
+/// Blocks of a `Button A`, a `Button B` and a `Prize` line, separated by an empty line
+fn read_arcades(input: &str, errors: &mut Vec<ParseError>) -> Vec<Arcade> {
+    const LINES: [(&str, char); 3] = [("Button A: ", '+'), ("Button B: ", '+'), ("Prize: ", '=')];
+    let expected_line = |row: usize| format!("a `{}` line", LINES[row].0.trim_end_matches(": "));
+    let mut arcades = Vec::new();
+    let mut pairs = Vec::new();
+    let mut row = 0;
+    for line in input.lines() {
+        if row == LINES.len() {
+            row = 0;
+            if line.is_empty() {
+                continue;
+            }
+            errors.push(ParseError::at(input, line, "an empty line after the prize"));
+        }
+        if line.is_empty() {
+            errors.push(ParseError::at(input, line, expected_line(row)));
+            row = 0;
+            pairs.clear();
+            continue;
+        }
+        let (label, separator) = LINES[row];
+        let pair = line
+            .strip_prefix(label)
+            .or_expected(input, line, &format!("`{}`", label.trim_end()))
+            .and_then(|rest| parse_pair(input, rest, separator));
+        match pair {
+            Ok(pair) => pairs.push(pair),
+            Err(err) => errors.push(err),
+        }
+        row += 1;
+        if row == LINES.len() {
+            if let [(ax, ay), (bx, by), prize] = pairs[..] {
+                arcades.push(Arcade {
+                    a: Button {
+                        x_move: ax,
+                        y_move: ay,
+                    },
+                    b: Button {
+                        x_move: bx,
+                        y_move: by,
+                    },
+                    prize,
+                });
+            }
+            pairs.clear();
+        }
+    }
+    if (1..LINES.len()).contains(&row) {
+        errors.push(ParseError::end(input, expected_line(row)));
+    }
+    arcades
+}
+
+/// Reads lines like `Button A: X+94, Y+34`, where `separator` comes before each number
+fn parse_pair(input: &str, line: &str, separator: char) -> Result<(i64, i64)> {
+    let (part1, part2) =
+        line.split_once(',')
+            .or_expected(input, line, "two coordinates separated by `,`")?;
+    Ok((
+        parse_coordinate(input, part1, separator)?,
+        parse_coordinate(input, part2, separator)?,
+    ))
+}
+
+fn parse_coordinate(input: &str, part: &str, separator: char) -> Result<i64> {
+    let (_, n) = part.split_once(separator).or_expected(
+        input,
+        part,
+        &format!("`{separator}` before the number"),
+    )?;
+    n.parse().or_expected(input, n, "a number")
+}
+
+/// Solving this as a system of equations
+///
+/// Ra = Pa*A1 + Pb*B1
+/// Rb = Pa*A2 + Pb*B2
+///
+/// Pa = (Ra*B2 - Rb*B1) / (A1*B2 - A2*B1)
+/// Pb = (Rb*A1 - Ra*A2) / (A1*B2 - A2*B1)
+///
+/// When the buttons move in the same direction the divisor is zero, and the prize is won along
+/// that line with `solve_on_line`. Presses that are negative, fractional or overflow mean that the
+/// prize cannot be won.
+fn solve(rx: i128, ry: i128, a1: i128, a2: i128, b1: i128, b2: i128) -> Option<(i128, i128)> {
+    let determinant = a1.checked_mul(b2)?.checked_sub(a2.checked_mul(b1)?)?;
+    if determinant == 0 {
+        let (d1, d2) = if (a1, a2) != (0, 0) {
+            (a1, a2)
+        } else {
+            (b1, b2)
+        };
+        if (d1, d2) == (0, 0) {
+            return ((rx, ry) == (0, 0)).then_some((0, 0));
+        }
+        if rx.checked_mul(d2)? != ry.checked_mul(d1)? {
+            return None;
+        }
+        return if d1 != 0 {
+            solve_on_line(rx, a1, b1)
+        } else {
+            solve_on_line(ry, a2, b2)
+        };
+    }
+    let pa_times_determinant = rx.checked_mul(b2)?.checked_sub(ry.checked_mul(b1)?)?;
+    let pb_times_determinant = ry.checked_mul(a1)?.checked_sub(rx.checked_mul(a2)?)?;
+    if pa_times_determinant.checked_rem(determinant)? != 0
+        || pb_times_determinant.checked_rem(determinant)? != 0
+    {
+        return None;
+    }
+    let pa = pa_times_determinant / determinant;
+    let pb = pb_times_determinant / determinant;
+    (pa >= 0 && pb >= 0).then_some((pa, pb))
+}
+
+/// Cheapest presses with `Pa*A + Pb*B = R` along a single coordinate
+///
+/// Every solution is `Pa = Pa0 + k*B/g` and `Pb = Pb0 - k*A/g`, from one solution of the extended
+/// Euclidean algorithm. Both presses must stay non negative, which bounds `k`, and the cost
+/// `3*Pa + Pb` is linear in `k`, so the cheapest presses are at one of the bounds. `A` and `B` are
+/// not both zero.
+fn solve_on_line(r: i128, a: i128, b: i128) -> Option<(i128, i128)> {
+    let (g, x, y) = extended_gcd(a, b);
+    if r.checked_rem(g)? != 0 {
+        return None;
+    }
+    let (pa0, pb0) = (x.checked_mul(r / g)?, y.checked_mul(r / g)?);
+    let (step_a, step_b) = (b / g, a / g);
+    let (mut low, mut high) = (None, None);
+    let mut bound = |step: i128, limit: i128, at_least: bool| -> Option<()> {
+        // `k * step >= limit` when `at_least`, `k * step <= limit` otherwise
+        match (step.signum(), at_least) {
+            (0, true) => (limit <= 0).then_some(()),
+            (0, false) => (limit >= 0).then_some(()),
+            (1, true) | (-1, false) => {
+                let k = div_ceil(limit, step);
+                low = Some(low.map_or(k, |low: i128| low.max(k)));
+                Some(())
+            }
+            _ => {
+                let k = div_floor(limit, step);
+                high = Some(high.map_or(k, |high: i128| high.min(k)));
+                Some(())
+            }
+        }
+    };
+    bound(step_a, pa0.checked_neg()?, true)?;
+    bound(step_b, pb0, false)?;
+    let slope = step_a.checked_mul(3)?.checked_sub(step_b)?;
+    let k = match (low, high) {
+        (Some(low), Some(high)) if low > high => return None,
+        (Some(low), Some(high)) => {
+            if slope >= 0 {
+                low
+            } else {
+                high
+            }
+        }
+        (Some(low), None) => low,
+        (None, Some(high)) => high,
+        (None, None) => unreachable!("A and B are not both zero"),
+    };
+    Some((
+        pa0.checked_add(k.checked_mul(step_a)?)?,
+        pb0.checked_sub(k.checked_mul(step_b)?)?,
+    ))
+}
+
+/// `(g, x, y)` with `a*x + b*y = g` and `g` the positive greatest common divisor
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a.abs(), a.signum(), 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+fn div_floor(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) != (b < 0) {
+        q - 1
+    } else {
+        q
+    }
+}
+
+fn div_ceil(a: i128, b: i128) -> i128 {
+    let q = a / b;
+    if a % b != 0 && (a < 0) == (b < 0) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Button {
+    x_move: i64,
+    y_move: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Arcade {
+    a: Button,
+    b: Button,
+    prize: (i64, i64),
+}
@@ -0,0 +1,194 @@
+use common::{all_errors, first_error, OrExpected, OrReport, ParseError, Puzzle, Result};
+
+pub struct Day14;
+
+/// Width and height of the space the robots walk in
+pub const MAP_SIZE: (usize, usize) = (101, 103);
+/// How long the robots walk before the safety factor is measured
+pub const SECONDS: usize = 100;
+
+impl Puzzle for Day14 {
+    type Input = Vec<Robot>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_robots(input, MAP_SIZE, errors)
+    }
+
+    fn part1(robots: &Self::Input) -> i64 {
+        safety_factor(robots, MAP_SIZE, SECONDS)
+    }
+
+    fn part2(robots: &Self::Input) -> i64 {
+        find_christmas_tree(robots, MAP_SIZE)
+    }
+}
+
+/// Product of the number of robots in each quadrant after `seconds`
+pub fn safety_factor(robots: &[Robot], map_size: (usize, usize), seconds: usize) -> i64 {
+    let mut robots = robots.to_vec();
+    for _ in 0..seconds {
+        for robot in robots.iter_mut() {
+            robot.walk(&map_size);
+        }
+    }
+    calculate_robots_per_quadrant(&map_size, &robots)
+        .iter()
+        .product()
+}
+
+/// Second at which the robots gather the most in one quadrant, which is when they draw the tree
+pub fn find_christmas_tree(robots: &[Robot], map_size: (usize, usize)) -> i64 {
+    let n = find_iteration_with_highest_quadrant_density(robots.to_vec(), &map_size, 10000) + 1;
+    log::info!("Chosen iteration {n}");
+    log::debug!(
+        "Robots after {n} seconds:\n{}",
+        draw_map(robots, map_size, n)
+    );
+    n as i64
+}
+
+/// The robots after `seconds`, as a `█` for every tile holding at least one
+pub fn draw_map(robots: &[Robot], map_size: (usize, usize), seconds: usize) -> String {
+    let mut robots = robots.to_vec();
+    for _ in 0..seconds {
+        for robot in robots.iter_mut() {
+            robot.walk(&map_size);
+        }
+    }
+    let mut map: Vec<Vec<char>> = (0..map_size.1)
+        .map(|_| (0..map_size.0).map(|_| ' ').collect())
+        .collect();
+    for robot in robots.iter() {
+        map[robot.position.1 as usize][robot.position.0 as usize] = '█';
+    }
+    map.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+/// The robots of a space `map_size` wide and tall
+pub fn read_input(input: &str, map_size: (usize, usize)) -> Result<Vec<Robot>> {
+    first_error(|errors| read_robots(input, map_size, errors))
+}
+
+/// Every problem of the robots, where [`read_input`] stops at the first.
+pub fn check_input(input: &str, map_size: (usize, usize)) -> Vec<ParseError> {
+    all_errors(|errors| read_robots(input, map_size, errors))
+}
+
+/// One robot per line, starting inside the map
+fn read_robots(input: &str, map_size: (usize, usize), errors: &mut Vec<ParseError>) -> Vec<Robot> {
+    input
+        .lines()
+        .filter_map(|line| read_robot(input, line, map_size).or_report(errors))
+        .collect()
+}
+
+fn read_robot(input: &str, line: &str, map_size: (usize, usize)) -> Result<Robot> {
+    let remainder = line.strip_prefix("p=").or_expected(input, line, "`p=`")?;
+    let (position, velocity) =
+        remainder
+            .split_once(' ')
+            .or_expected(input, remainder, "a space before the velocity")?;
+    let velocity = velocity
+        .strip_prefix("v=")
+        .or_expected(input, velocity, "`v=`")?;
+    let (x, y) = split_pair(input, position)?;
+    let inside = |n: &str, size: usize| {
+        n.parse()
+            .ok()
+            .filter(|n| (0..size as i64).contains(n))
+            .or_expected(input, n, &format!("a position between 0 and {}", size - 1))
+    };
+    let (vx, vy) = split_pair(input, velocity)?;
+    Ok(Robot {
+        position: (inside(x, map_size.0)?, inside(y, map_size.1)?),
+        velocity: (
+            vx.parse().or_expected(input, vx, "a number")?,
+            vy.parse().or_expected(input, vy, "a number")?,
+        ),
+    })
+}
+
+fn split_pair<'a>(input: &str, pair: &'a str) -> Result<(&'a str, &'a str)> {
+    pair.split_once(',')
+        .or_expected(input, pair, "two numbers separated by `,`")
+}
+
+fn calculate_robots_per_quadrant(map_size: &(usize, usize), robots: &[Robot]) -> Vec<i64> {
+    let quadrant_1 = (0..(map_size.0 / 2), 0..map_size.1 / 2);
+    let quadrant_2 = (((map_size.0 / 2) + 1)..map_size.0, 0..map_size.1 / 2);
+    let quadrant_3 = (0..(map_size.0 / 2), ((map_size.1 / 2) + 1)..map_size.1);
+    let quadrant_4 = (
+        ((map_size.0 / 2) + 1)..map_size.0,
+        ((map_size.1 / 2) + 1)..map_size.1,
+    );
+    let mut n_quadrant_1 = 0;
+    let mut n_quadrant_2 = 0;
+    let mut n_quadrant_3 = 0;
+    let mut n_quadrant_4 = 0;
+    for robot in robots {
+        if quadrant_1.0.contains(&(robot.position.0 as usize))
+            && quadrant_1.1.contains(&(robot.position.1 as usize))
+        {
+            n_quadrant_1 += 1;
+            continue;
+        }
+        if quadrant_2.0.contains(&(robot.position.0 as usize))
+            && quadrant_2.1.contains(&(robot.position.1 as usize))
+        {
+            n_quadrant_2 += 1;
+            continue;
+        }
+        if quadrant_3.0.contains(&(robot.position.0 as usize))
+            && quadrant_3.1.contains(&(robot.position.1 as usize))
+        {
+            n_quadrant_3 += 1;
+            continue;
+        }
+        if quadrant_4.0.contains(&(robot.position.0 as usize))
+            && quadrant_4.1.contains(&(robot.position.1 as usize))
+        {
+            n_quadrant_4 += 1;
+            continue;
+        }
+    }
+    vec![n_quadrant_1, n_quadrant_2, n_quadrant_3, n_quadrant_4]
+}
+
+fn find_iteration_with_highest_quadrant_density(
+    mut robots: Vec<Robot>,
+    map_size: &(usize, usize),
+    limit: usize,
+) -> usize {
+    let mut highest_quadrant_density = 0;
+    let mut chosen_iteration = 0;
+    for i in 0..limit {
+        for robot in robots.iter_mut() {
+            robot.walk(map_size);
+        }
+        let mut quadrants = calculate_robots_per_quadrant(map_size, &robots);
+        quadrants.sort();
+        if quadrants[3] > highest_quadrant_density {
+            highest_quadrant_density = quadrants[3];
+            chosen_iteration = i;
+        }
+    }
+    chosen_iteration
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    position: (i64, i64),
+    velocity: (i64, i64),
+}
+
+impl Robot {
+    pub fn walk(&mut self, map_size: &(usize, usize)) {
+        let (width, height) = (map_size.0 as i64, map_size.1 as i64);
+        self.position.0 = (self.position.0 + self.velocity.0.rem_euclid(width)).rem_euclid(width);
+        self.position.1 = (self.position.1 + self.velocity.1.rem_euclid(height)).rem_euclid(height);
+    }
+}
@@ -0,0 +1,275 @@
+use common::{split_at_empty_line, OrExpected, OrReport, ParseError, Puzzle};
+use grid::{Direction, Grid, Point};
+use std::mem;
+
+pub struct Day15;
+
+pub struct Warehouse {
+    map: Grid<Tile>,
+    movements: Vec<Direction>,
+    robot: Point,
+}
+
+impl Puzzle for Day15 {
+    type Input = Warehouse;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1(warehouse: &Self::Input) -> usize {
+        let map = after_moves(warehouse);
+        log::debug!("Warehouse after the robot moved:\n{}", draw(&map));
+        sum_all_boxes_coordinates(&map)
+    }
+
+    fn part2(warehouse: &Self::Input) -> usize {
+        let map = wide_after_moves(warehouse);
+        log::debug!("Warehouse after the robot moved:\n{}", draw(&map));
+        sum_all_boxes_coordinates(&map)
+    }
+}
+
+/// The warehouse once the robot made every movement, drawn like the puzzle does
+pub fn draw_after_moves(warehouse: &Warehouse) -> String {
+    draw(&after_moves(warehouse))
+}
+
+/// The twice as wide warehouse of the second part once the robot made every movement
+pub fn draw_wide_after_moves(warehouse: &Warehouse) -> String {
+    draw(&wide_after_moves(warehouse))
+}
+
+fn draw(map: &Grid<Tile>) -> String {
+    map.render(Tile::to_char)
+}
+
+fn after_moves(warehouse: &Warehouse) -> Grid<Tile> {
+    let mut map = warehouse.map.clone();
+    let mut robot_position = warehouse.robot;
+    for movement in warehouse.movements.iter() {
+        if let Some(position) = try_to_move(robot_position, *movement, &mut map) {
+            robot_position = position;
+        }
+    }
+    map
+}
+
+fn wide_after_moves(warehouse: &Warehouse) -> Grid<Tile> {
+    let mut map = widen(&warehouse.map);
+    let mut robot_position = Point::new(warehouse.robot.x * 2, warehouse.robot.y);
+    for movement in warehouse.movements.iter() {
+        if can_move(robot_position, *movement, &map) {
+            robot_position = do_move(robot_position, *movement, &mut map);
+        }
+    }
+    map
+}
+
+/// A map walled in by `#` with a single robot `@`, an empty line and the movements
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> Warehouse {
+    // The map keeps its last line break, so a short last row does not end the input
+    let (map_lines, movements) = match split_at_empty_line(input) {
+        Some(parts) => parts,
+        None => {
+            errors.push(ParseError::end(
+                input,
+                "an empty line between the map and the movements",
+            ));
+            (input, "")
+        }
+    };
+    let map = Grid::read(
+        map_lines,
+        |c| Tile::from_char(c).filter(|tile| !matches!(tile, Tile::WideBoxL | Tile::WideBoxR)),
+        errors,
+    );
+    check_walls(input, map_lines, errors);
+    Warehouse {
+        map,
+        robot: grid::find_single(input, '@', "a robot `@` in the map", errors),
+        movements: read_movements(input, movements, errors),
+    }
+}
+
+/// Every tile on the border of the map is a wall, so nothing can leave the warehouse
+fn check_walls(input: &str, map: &str, errors: &mut Vec<ParseError>) {
+    let height = map.lines().count();
+    for (y, line) in map.lines().enumerate() {
+        let width = line.len();
+        for (x, tile) in line.char_indices() {
+            let border = y == 0 || y + 1 == height || x == 0 || x + 1 == width;
+            if border && tile != '#' {
+                errors.push(ParseError::at(
+                    input,
+                    &line[x..],
+                    "a wall `#` around the warehouse",
+                ));
+            }
+        }
+    }
+}
+
+/// Everything except the robot is twice as wide in the second warehouse
+fn widen(map: &Grid<Tile>) -> Grid<Tile> {
+    let mut wide_map = Grid::new(map.width() * 2, map.height(), Tile::Empty);
+    for (position, tile) in map.iter() {
+        let (left, right) = match tile {
+            Tile::Wall => (Tile::Wall, Tile::Wall),
+            Tile::Box => (Tile::WideBoxL, Tile::WideBoxR),
+            Tile::Robot => (Tile::Robot, Tile::Empty),
+            Tile::Empty | Tile::WideBoxL | Tile::WideBoxR => (Tile::Empty, Tile::Empty),
+        };
+        wide_map[Point::new(position.x * 2, position.y)] = left;
+        wide_map[Point::new(position.x * 2 + 1, position.y)] = right;
+    }
+    wide_map
+}
+
+fn read_movements(input: &str, movements: &str, errors: &mut Vec<ParseError>) -> Vec<Direction> {
+    movements
+        .char_indices()
+        .filter(|(_, c)| !c.is_whitespace())
+        .filter_map(|(index, c)| {
+            Direction::from_arrow(c)
+                .or_expected(input, &movements[index..], "one of `^>v<`")
+                .or_report(errors)
+        })
+        .collect()
+}
+
+/// Every tile that can move is inside the walls surrounding the warehouse
+fn next_position(position: Point, movement: Direction) -> Point {
+    position
+        .step(movement)
+        .expect("The warehouse should be surrounded by walls")
+}
+
+fn try_to_move(position: Point, movement: Direction, map: &mut Grid<Tile>) -> Option<Point> {
+    if map[position] == Tile::Wall {
+        return None;
+    }
+    let desired_new_position = next_position(position, movement);
+    if map[desired_new_position] == Tile::Empty {
+        let tile = mem::take(&mut map[position]);
+        map[desired_new_position] = tile;
+        return Some(desired_new_position);
+    }
+    if try_to_move(desired_new_position, movement, map).is_some() {
+        let tile = mem::take(&mut map[position]);
+        map[desired_new_position] = tile;
+        return Some(desired_new_position);
+    }
+    None
+}
+
+fn can_move(position: Point, movement: Direction, map: &Grid<Tile>) -> bool {
+    match map[position] {
+        Tile::Wall => false,
+        Tile::Empty => true,
+        Tile::Robot | Tile::Box => can_move(next_position(position, movement), movement, map),
+        Tile::WideBoxL if movement.is_vertical() => {
+            let desired_new_position = next_position(position, movement);
+            can_move(desired_new_position, movement, map)
+                && can_move(
+                    next_position(desired_new_position, Direction::Right),
+                    movement,
+                    map,
+                )
+        }
+        Tile::WideBoxL => can_move(next_position(position, movement), movement, map),
+        Tile::WideBoxR if movement.is_vertical() => {
+            let desired_new_position = next_position(position, movement);
+            can_move(desired_new_position, movement, map)
+                && can_move(
+                    next_position(desired_new_position, Direction::Left),
+                    movement,
+                    map,
+                )
+        }
+        Tile::WideBoxR => can_move(next_position(position, movement), movement, map),
+    }
+}
+
+fn do_move(position: Point, movement: Direction, map: &mut Grid<Tile>) -> Point {
+    match map[position] {
+        Tile::Wall => panic!("You should not move a wall"),
+        Tile::Empty => position,
+        Tile::Robot | Tile::Box => {
+            let desired_new_position = next_position(position, movement);
+            do_move(desired_new_position, movement, map);
+            let tile = mem::take(&mut map[position]);
+            map[desired_new_position] = tile;
+            desired_new_position
+        }
+        Tile::WideBoxL => move_wide_box(position, Direction::Right, movement, map),
+        Tile::WideBoxR => move_wide_box(position, Direction::Left, movement, map),
+    }
+}
+
+/// Moves both halves of a wide box, `other_half` being the side where its pair is
+fn move_wide_box(
+    position: Point,
+    other_half: Direction,
+    movement: Direction,
+    map: &mut Grid<Tile>,
+) -> Point {
+    let desired_new_position = next_position(position, movement);
+    do_move(desired_new_position, movement, map);
+    if movement.is_vertical() {
+        let pair_position = next_position(position, other_half);
+        let desired_pair_position = next_position(desired_new_position, other_half);
+        do_move(desired_pair_position, movement, map);
+        let tile_2 = mem::take(&mut map[pair_position]);
+        map[desired_pair_position] = tile_2;
+    }
+
+    let tile_1 = mem::take(&mut map[position]);
+    map[desired_new_position] = tile_1;
+    desired_new_position
+}
+
+fn sum_all_boxes_coordinates(map: &Grid<Tile>) -> usize {
+    map.iter()
+        .filter(|(_, tile)| matches!(tile, Tile::Box | Tile::WideBoxL))
+        .map(|(position, _)| position.y * 100 + position.x)
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+enum Tile {
+    #[default]
+    Empty,
+    Wall,
+    Box,
+    WideBoxL,
+    WideBoxR,
+    Robot,
+}
+
+impl Tile {
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Tile::Empty),
+            '#' => Some(Tile::Wall),
+            'O' => Some(Tile::Box),
+            '@' => Some(Tile::Robot),
+            '[' => Some(Tile::WideBoxL),
+            ']' => Some(Tile::WideBoxR),
+            _ => None,
+        }
+    }
+
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Box => 'O',
+            Tile::Empty => '.',
+            Tile::Robot => '@',
+            Tile::Wall => '#',
+            Tile::WideBoxL => '[',
+            Tile::WideBoxR => ']',
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use common::{ParseError, Puzzle};
+use grid::{Direction, Grid, Point};
+use search::Paths;
+use std::{collections::HashSet, fmt::Debug};
+
+pub struct Day16;
+
+impl Puzzle for Day16 {
+    type Input = Map;
+    type Answer1 = Option<u64>;
+    type Answer2 = Option<usize>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1(map: &Self::Input) -> Option<u64> {
+        Some(find_best_paths(map)?.cost)
+    }
+
+    fn part2(map: &Self::Input) -> Option<usize> {
+        Some(tiles_in_best_paths(map)?.len())
+    }
+}
+
+/// The maze with an `O` on every tile of the best paths, and none when the destination is walled
+/// off
+pub fn draw_best_paths(map: &Map) -> String {
+    let tiles_in_best_paths = tiles_in_best_paths(map).unwrap_or_default();
+    let mut tiles = map.walls.map(|wall| if *wall { '#' } else { '.' });
+    for position in tiles_in_best_paths {
+        tiles[position] = 'O';
+    }
+    tiles.to_string()
+}
+
+fn tiles_in_best_paths(map: &Map) -> Option<HashSet<Point>> {
+    Some(
+        find_best_paths(map)?
+            .states()
+            .into_iter()
+            .map(|reindeer| reindeer.position)
+            .collect(),
+    )
+}
+
+/// Moving forward costs 1 and turning before the step costs 1000 more. `None` when walls cut the
+/// destination off.
+fn find_best_paths(map: &Map) -> Option<Paths<Reindeer>> {
+    search::dijkstra_all(
+        map.reindeer,
+        |reindeer| {
+            let reindeer = *reindeer;
+            [
+                (reindeer.direction, 1),
+                (reindeer.direction.turn_left(), 1001),
+                (reindeer.direction.turn_right(), 1001),
+            ]
+            .into_iter()
+            .filter_map(move |(direction, cost)| {
+                let position = map.walls.step(reindeer.position, direction)?;
+                (!map.walls[position]).then_some((Reindeer::new(position, direction), cost))
+            })
+        },
+        |reindeer| reindeer.position == map.destination,
+    )
+}
+
+/// A maze of walls `#` and tiles `.` with a single start `S` and destination `E`
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> Map {
+    Map {
+        walls: Grid::read(input, |c| "#.SE".contains(c).then_some(c == '#'), errors),
+        reindeer: Reindeer::new(
+            grid::find_single(input, 'S', "a reindeer start `S` in the maze", errors),
+            Direction::Right,
+        ),
+        destination: grid::find_single(input, 'E', "a destination `E` in the maze", errors),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Map {
+    walls: Grid<bool>,
+    reindeer: Reindeer,
+    destination: Point,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+struct Reindeer {
+    position: Point,
+    direction: Direction,
+}
+
+impl Reindeer {
+    pub fn new(position: Point, direction: Direction) -> Self {
+        Self {
+            position,
+            direction,
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use common::{split_at_empty_line, OrExpected, OrReport, ParseError, Puzzle, Result};
+
+pub struct Day17;
+
+/// Instructions a run may take before it counts as stuck in a loop. The real programs stop after a
+/// few hundred.
+pub const MAX_STEPS: usize = 1_000_000;
+
+impl Puzzle for Day17 {
+    /// The initial state of the computer and the program it runs
+    type Input = Program;
+    /// `None` when the program does not stop within [`MAX_STEPS`] instructions
+    type Answer1 = Option<String>;
+    /// `None` when no value of register A makes the program output itself
+    type Answer2 = Option<i64>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1(program: &Self::Input) -> Option<String> {
+        let mut computer = program.computer.clone();
+        Some(computer.execute(&program.instructions)?.join(","))
+    }
+
+    fn part2(program: &Self::Input) -> Option<i64> {
+        search_register_a(
+            0,
+            program.instructions.len() as isize - 1,
+            &program.computer,
+            &program.instructions,
+            8,
+        )
+    }
+}
+
+#[derive(Default)]
+pub struct Program {
+    computer: Computer,
+    instructions: Vec<u8>,
+}
+
+fn search_register_a(
+    mut register_a: i64,
+    mut cursor: isize,
+    computer: &Computer,
+    instructions: &[u8],
+    mut limit: usize,
+) -> Option<i64> {
+    let desired_output = instructions
+        .iter()
+        .map(|n| n.to_string())
+        .reduce(|mut acc, n| {
+            acc.push(',');
+            acc += &n;
+            acc
+        })?;
+    loop {
+        limit -= 1;
+        if limit == 0 {
+            break;
+        }
+        let mut computer_copied = computer.clone();
+        computer_copied.register_a = register_a;
+        let Some(result) = computer_copied.execute(instructions) else {
+            register_a += 1;
+            continue;
+        };
+        if result.join(",") == desired_output {
+            return Some(register_a);
+        }
+        if cursor < 0 {
+            break;
+        }
+        if result.first() == Some(&instructions[cursor as usize].to_string()) {
+            if let Some(solution) =
+                search_register_a(register_a + 1, cursor, computer, instructions, limit)
+            {
+                return Some(solution);
+            }
+            limit = 8;
+            cursor -= 1;
+            register_a = register_a.checked_mul(8)?;
+            continue;
+        }
+        register_a += 1;
+    }
+    None
+}
+
+/// Registers A, B and C, an empty line and the program
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> Program {
+    let Some((registers_part, instructions_part)) = split_at_empty_line(input) else {
+        errors.push(ParseError::end(input, "an empty line after the registers"));
+        return Program::default();
+    };
+    let mut registers = registers_part.lines();
+    let end_of_registers = &registers_part[registers_part.len()..];
+    let mut read_register = |name: &str| -> Result<i64> {
+        let line = registers.next().unwrap_or(end_of_registers);
+        let (_, value) = line.split_once(&format!("{name}:")).or_expected(
+            input,
+            line,
+            &format!("`Register {name}:`"),
+        )?;
+        let value = value.trim();
+        value.parse().or_expected(input, value, "a number")
+    };
+    let computer = Computer {
+        pc_counter: 0,
+        register_a: read_register("A").or_report(errors).unwrap_or_default(),
+        register_b: read_register("B").or_report(errors).unwrap_or_default(),
+        register_c: read_register("C").or_report(errors).unwrap_or_default(),
+    };
+    let Some((_, program)) = instructions_part.split_once(": ") else {
+        errors.push(ParseError::at(input, instructions_part, "`Program: `"));
+        return Program::default();
+    };
+    let instructions: Vec<u8> = program
+        .split(',')
+        .map(|p| p.trim())
+        .filter(|p| !p.is_empty())
+        .filter_map(|p| {
+            p.parse()
+                .ok()
+                .filter(|n| *n < 8)
+                .or_expected(input, p, "a 3-bit number")
+                .or_report(errors)
+        })
+        .collect();
+    if instructions.is_empty() || !instructions.len().is_multiple_of(2) {
+        errors.push(ParseError::at(
+            input,
+            program.trim_end(),
+            "pairs of opcodes and operands",
+        ));
+    }
+    Program {
+        computer,
+        instructions,
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct Computer {
+    register_a: i64,
+    register_b: i64,
+    register_c: i64,
+    pc_counter: usize,
+}
+
+impl Computer {
+    /// The outputs of the program, or `None` when it is still running after [`MAX_STEPS`]
+    /// instructions. The program stops when the counter points past an opcode and its operand.
+    pub fn execute(&mut self, input: &[u8]) -> Option<Vec<String>> {
+        let mut result = Vec::new();
+        for _ in 0..MAX_STEPS {
+            let (Some(opcode), Some(&operand)) =
+                (input.get(self.pc_counter), input.get(self.pc_counter + 1))
+            else {
+                return Some(result);
+            };
+            let opcode = OpCode::from_u8(*opcode);
+            let combo_operand = self.read_combo_operand(operand);
+            match opcode {
+                OpCode::Adv => {
+                    self.register_a /= 1i64.checked_shl(combo_operand as u32).unwrap_or(i64::MAX)
+                }
+                OpCode::Bxl => self.register_b ^= operand as i64,
+                OpCode::Bst => self.register_b = combo_operand % 8,
+                OpCode::Jnz if self.register_a != 0 => {
+                    self.pc_counter = (operand / 2) as usize;
+                    continue;
+                }
+                OpCode::Jnz => {}
+                OpCode::Bxc => self.register_b ^= self.register_c,
+                OpCode::Out => result.push((combo_operand % 8).to_string()),
+                OpCode::Bdv => {
+                    self.register_b =
+                        self.register_a / 1i64.checked_shl(combo_operand as u32).unwrap_or(i64::MAX)
+                }
+                OpCode::Cdv => {
+                    self.register_c =
+                        self.register_a / 1i64.checked_shl(combo_operand as u32).unwrap_or(i64::MAX)
+                }
+            }
+            self.pc_counter += 2
+        }
+        None
+    }
+
+    fn read_combo_operand(&self, operand: u8) -> i64 {
+        match operand {
+            0..=3 => operand as i64,
+            4 => self.register_a,
+            5 => self.register_b,
+            6 => self.register_c,
+            _ => 0,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum OpCode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl OpCode {
+    pub fn from_u8(input: u8) -> Self {
+        match input {
+            0 => Self::Adv,
+            1 => Self::Bxl,
+            2 => Self::Bst,
+            3 => Self::Jnz,
+            4 => Self::Bxc,
+            5 => Self::Out,
+            6 => Self::Bdv,
+            7 => Self::Cdv,
+            _ => unreachable!("No opcode"),
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use common::{all_errors, first_error, OrExpected, OrReport, ParseError, Puzzle, Result};
+use grid::{Grid, Point};
+
+pub struct Day18;
+
+/// The largest coordinate of the memory space
+pub const GRID_SIZE: usize = 70;
+/// How many bytes have fallen in the first part
+pub const BYTES: usize = 1024;
+
+impl Puzzle for Day18 {
+    /// Where each byte falls, in order
+    type Input = Vec<Point>;
+    /// `None` when the first 1024 bytes already cut the exit off
+    type Answer1 = Option<u64>;
+    /// `None` when the exit stays reachable once every byte has fallen
+    type Answer2 = Option<String>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_bytes(input, GRID_SIZE, errors)
+    }
+
+    fn part1(bytes_to_fall: &Self::Input) -> Option<u64> {
+        shortest_path_after(bytes_to_fall, GRID_SIZE, BYTES)
+    }
+
+    fn part2(bytes_to_fall: &Self::Input) -> Option<String> {
+        first_blocking_byte(bytes_to_fall, GRID_SIZE)
+    }
+}
+
+/// Fewest steps to the exit once the first `bytes_fallen` bytes have fallen, `None` when they cut
+/// it off
+pub fn shortest_path_after(
+    bytes_to_fall: &[Point],
+    grid_size: usize,
+    bytes_fallen: usize,
+) -> Option<u64> {
+    let fallen_bytes = corrupt_memory(
+        grid_size,
+        &bytes_to_fall[..bytes_fallen.min(bytes_to_fall.len())],
+    );
+    find_path_cost(grid_size, &fallen_bytes)
+}
+
+/// Like [`first_blocking_byte`], looking for a path after every byte. `None` when the exit stays
+/// reachable.
+pub fn first_blocking_byte_brute_force(
+    bytes_to_fall: &[Point],
+    grid_size: usize,
+) -> Option<String> {
+    let mut fallen_bytes = corrupt_memory(grid_size, &[]);
+    for byte in bytes_to_fall {
+        fallen_bytes[*byte] = true;
+        if !has_path(grid_size, &fallen_bytes) {
+            return Some(format!("{},{}", byte.x, byte.y));
+        }
+    }
+    None
+}
+
+/// Coordinates of the first byte that cuts the exit off, found by binary search. `None` when the
+/// exit stays reachable.
+pub fn first_blocking_byte(bytes_to_fall: &[Point], grid_size: usize) -> Option<String> {
+    let has_path_after = |bytes_fallen: usize| {
+        has_path(
+            grid_size,
+            &corrupt_memory(grid_size, &bytes_to_fall[..bytes_fallen]),
+        )
+    };
+    if has_path_after(bytes_to_fall.len()) {
+        return None;
+    }
+    // The exit is reachable after `start` bytes and cut off after `end` bytes
+    let mut start = 0;
+    let mut end = bytes_to_fall.len();
+    while end - start > 1 {
+        let mid = (start + end) / 2;
+        if has_path_after(mid) {
+            start = mid;
+        } else {
+            end = mid;
+        }
+    }
+    let byte = bytes_to_fall[end - 1];
+    Some(format!("{},{}", byte.x, byte.y))
+}
+
+/// The memory space once the first `bytes_fallen` bytes have fallen, with a `#` on each of them and
+/// an `O` on every tile of one of the shortest paths to the exit
+pub fn draw_memory(bytes_to_fall: &[Point], grid_size: usize, bytes_fallen: usize) -> String {
+    let fallen_bytes = corrupt_memory(
+        grid_size,
+        &bytes_to_fall[..bytes_fallen.min(bytes_to_fall.len())],
+    );
+    let from_start = search::distances(Point::new(0, 0), |position| {
+        free_neighbours(&fallen_bytes, *position)
+    });
+    let mut map = fallen_bytes.map(|corrupted| if *corrupted { '#' } else { '.' });
+    // Walks back from the exit, each time to a tile one step closer to the start
+    let mut position = Point::new(grid_size, grid_size);
+    if let Some(mut steps) = from_start.get(&position).copied() {
+        map[position] = 'O';
+        while steps > 0 {
+            steps -= 1;
+            position = free_neighbours(&fallen_bytes, position)
+                .find(|neighbour| from_start.get(neighbour) == Some(&steps))
+                .expect("A tile of the path has a neighbour one step closer to the start");
+            map[position] = 'O';
+        }
+    }
+    map.to_string()
+}
+
+/// The memory space goes from `0` to `grid_size` on both axes, `true` being a corrupted byte
+fn corrupt_memory(grid_size: usize, fallen_bytes: &[Point]) -> Grid<bool> {
+    let mut memory = Grid::new(grid_size + 1, grid_size + 1, false);
+    for byte in fallen_bytes {
+        memory[*byte] = true;
+    }
+    memory
+}
+
+/// Fewest steps from the top left corner to the bottom right one
+fn find_path_cost(grid_size: usize, fallen_bytes: &Grid<bool>) -> Option<u64> {
+    let exit = Point::new(grid_size, grid_size);
+    search::astar(
+        Point::new(0, 0),
+        |position| free_neighbours(fallen_bytes, *position).map(|next| (next, 1)),
+        |position| position.manhattan_distance(exit) as u64,
+        |position| *position == exit,
+    )
+    .map(|(_, cost)| cost)
+}
+
+fn has_path(grid_size: usize, fallen_bytes: &Grid<bool>) -> bool {
+    let exit = Point::new(grid_size, grid_size);
+    search::bfs(
+        Point::new(0, 0),
+        |position| free_neighbours(fallen_bytes, *position),
+        |position| *position == exit,
+    )
+    .is_some()
+}
+
+fn free_neighbours(fallen_bytes: &Grid<bool>, position: Point) -> impl Iterator<Item = Point> + '_ {
+    fallen_bytes
+        .neighbours4(position)
+        .filter(|neighbour| !fallen_bytes[*neighbour])
+}
+
+/// The bytes of a memory space going from `0` to `grid_size` on both axes
+pub fn read_input(input: &str, grid_size: usize) -> Result<Vec<Point>> {
+    first_error(|errors| read_bytes(input, grid_size, errors))
+}
+
+/// Every problem of the bytes, where [`read_input`] stops at the first.
+pub fn check_input(input: &str, grid_size: usize) -> Vec<ParseError> {
+    all_errors(|errors| read_bytes(input, grid_size, errors))
+}
+
+/// One byte per line, inside the memory space
+fn read_bytes(input: &str, grid_size: usize, errors: &mut Vec<ParseError>) -> Vec<Point> {
+    input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .filter_map(|line| read_byte(input, line, grid_size).or_report(errors))
+        .collect()
+}
+
+fn read_byte(input: &str, line: &str, grid_size: usize) -> Result<Point> {
+    let (x, y) =
+        line.split_once(',')
+            .or_expected(input, line, "two coordinates separated by `,`")?;
+    let expected = format!("a coordinate between 0 and {grid_size}");
+    Ok(Point::new(
+        x.parse()
+            .ok()
+            .filter(|x| *x <= grid_size)
+            .or_expected(input, x, &expected)?,
+        y.parse()
+            .ok()
+            .filter(|y| *y <= grid_size)
+            .or_expected(input, y, &expected)?,
+    ))
+}
@@ -0,0 +1,123 @@
+use common::{split_at_empty_line, OrExpected, OrReport, ParseError, Puzzle, Result};
+use std::collections::HashMap;
+
+pub struct Day19;
+
+impl Puzzle for Day19 {
+    /// The sorted available towels and the desired designs
+    type Input = (Vec<String>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1((available_towels, desired_patterns): &Self::Input) -> usize {
+        desired_patterns
+            .iter()
+            .filter(|pattern| is_possible(pattern, available_towels.clone()))
+            .count()
+    }
+
+    fn part2((available_towels, desired_patterns): &Self::Input) -> usize {
+        let mut memoization: HashMap<String, usize> = HashMap::new();
+        desired_patterns
+            .iter()
+            .map(|pattern| n_possibilities(pattern, available_towels.clone(), &mut memoization))
+            .sum()
+    }
+}
+
+/// Whether the towels, sorted, can make `pattern`
+pub fn is_possible(pattern: &str, available_towels: Vec<String>) -> bool {
+    if pattern.is_empty() {
+        return true;
+    }
+    let mut my_available_towels = available_towels.clone();
+    while let Ok(position) = my_available_towels.binary_search_by(|towel| {
+        towel
+            .chars()
+            .next()
+            .unwrap()
+            .cmp(&pattern.chars().next().unwrap())
+    }) {
+        let towel = my_available_towels.remove(position);
+        if towel.len() > pattern.len() {
+            continue;
+        }
+        if towel == pattern[0..towel.len()]
+            && is_possible(&pattern[towel.len()..], available_towels.clone())
+        {
+            return true;
+        }
+    }
+    false
+}
+
+/// The number of arrangements of towels that make `pattern`
+pub fn n_possibilities(
+    pattern: &str,
+    available_towels: Vec<String>,
+    memoization: &mut HashMap<String, usize>,
+) -> usize {
+    if pattern.is_empty() {
+        return 1;
+    }
+    if let Some(n) = memoization.get(pattern) {
+        return *n;
+    }
+
+    let mut my_available_towels = available_towels.clone();
+    let mut count = 0;
+    while let Ok(position) = my_available_towels.binary_search_by(|towel| {
+        towel
+            .chars()
+            .next()
+            .unwrap()
+            .cmp(&pattern.chars().next().unwrap())
+    }) {
+        let towel = my_available_towels.remove(position);
+        if towel.len() > pattern.len() {
+            continue;
+        }
+        if towel == pattern[0..towel.len()] {
+            count += n_possibilities(
+                &pattern[towel.len()..],
+                available_towels.clone(),
+                memoization,
+            )
+        }
+    }
+    memoization.insert(pattern.to_string(), count);
+    count
+}
+
+/// The towels separated by `,`, an empty line and one design per line
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> (Vec<String>, Vec<String>) {
+    let Some((available_towels, desired_patterns)) = split_at_empty_line(input) else {
+        errors.push(ParseError::end(
+            input,
+            "an empty line between the towels and the designs",
+        ));
+        return (Vec::new(), Vec::new());
+    };
+    let mut available_towels: Vec<String> = available_towels
+        .split(',')
+        .filter_map(|towel| read_stripes(input, towel.trim()).or_report(errors))
+        .collect();
+    available_towels.sort();
+    let desired_patterns = desired_patterns
+        .lines()
+        .filter(|pattern| !pattern.is_empty())
+        .filter_map(|pattern| read_stripes(input, pattern.trim()).or_report(errors))
+        .collect();
+    (available_towels, desired_patterns)
+}
+
+/// Towels and designs are made of white, blue, black, red or green stripes
+fn read_stripes(input: &str, stripes: &str) -> Result<String> {
+    (!stripes.is_empty() && stripes.chars().all(|c| "wubrg".contains(c)))
+        .then(|| stripes.to_string())
+        .or_expected(input, stripes, "stripes of `w`, `u`, `b`, `r` or `g`")
+}
@@ -0,0 +1,84 @@
+use common::{OrExpected, OrReport, ParseError, Puzzle};
+
+pub struct Day2;
+
+impl Puzzle for Day2 {
+    type Input = Vec<Vec<i64>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_reports(input, errors)
+    }
+
+    fn part1(reports: &Self::Input) -> usize {
+        reports
+            .iter()
+            .filter(|report| is_valid_report(report))
+            .count()
+    }
+
+    fn part2(reports: &Self::Input) -> usize {
+        let mut n_valids = 0;
+        for report in reports {
+            if is_valid_report(report) {
+                n_valids += 1;
+                continue;
+            }
+            for n in 0..report.len() {
+                if is_valid_report(&([&report[0..n], &report[n + 1..report.len()]]).concat()) {
+                    n_valids += 1;
+                    break;
+                }
+            }
+        }
+        n_valids
+    }
+}
+
+/// One report of levels separated by spaces per line
+fn read_reports(input: &str, errors: &mut Vec<ParseError>) -> Vec<Vec<i64>> {
+    input
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .filter_map(|level| {
+                    level
+                        .parse()
+                        .or_expected(input, level, "a number")
+                        .or_report(errors)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+enum Order {
+    Increasing,
+    Decreasing,
+}
+
+fn is_valid_report(report: &[i64]) -> bool {
+    let mut order = None;
+    for i in 1..report.len() {
+        let diff = (report[i - 1] - report[i]).abs();
+        if !(1..=3).contains(&diff) {
+            return false;
+        }
+        match order {
+            None if report[i - 1] > report[i] => order = Some(Order::Decreasing),
+            None => order = Some(Order::Increasing),
+            Some(Order::Increasing) => {
+                if report[i - 1] > report[i] {
+                    return false;
+                }
+            }
+            Some(Order::Decreasing) => {
+                if report[i - 1] < report[i] {
+                    return false;
+                }
+            }
+        }
+    }
+    true
+}
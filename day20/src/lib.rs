@@ -0,0 +1,143 @@
+use common::{ParseError, Puzzle};
+use grid::{Direction, Grid, Point};
+use std::collections::HashMap;
+
+pub struct Day20;
+
+/// Picoseconds a cheat must save to be counted, in both parts
+pub const MIN_SAVING: u64 = 100;
+
+impl Puzzle for Day20 {
+    type Input = Racetrack;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1(racetrack: &Self::Input) -> usize {
+        count_short_cheats(racetrack, MIN_SAVING)
+    }
+
+    fn part2(racetrack: &Self::Input) -> usize {
+        count_long_cheats(racetrack, MIN_SAVING)
+    }
+}
+
+/// Cheats through a single wall saving at least `limit` picoseconds
+pub fn count_short_cheats((walls, start, _end): &Racetrack, limit: u64) -> usize {
+    let time_matrix = calculate_time_of_race(walls, *start);
+    let cheats = calculate_possible_cheats(&time_matrix, walls);
+    cheats.into_iter().filter(|cheat| *cheat >= limit).count()
+}
+
+/// Cheats of up to 20 picoseconds saving at least `limit` picoseconds
+pub fn count_long_cheats((walls, start, _end): &Racetrack, limit: u64) -> usize {
+    let time_matrix = calculate_time_of_race(walls, *start);
+    let cheats = calculate_possible_cheats_2(&time_matrix);
+    cheats.into_iter().filter(|cheat| *cheat >= limit).count()
+}
+
+/// The racetrack with an `X` on every wall that a cheat through a single wall saving at least
+/// `limit` picoseconds goes through
+pub fn draw_short_cheats((walls, start, end): &Racetrack, limit: u64) -> String {
+    let time_matrix = calculate_time_of_race(walls, *start);
+    let mut map = walls.map(|wall| if *wall { '#' } else { '.' });
+    for (wall, _) in walls.iter().filter(|(_, is_wall)| **is_wall) {
+        let saves_enough = [Direction::Up, Direction::Left]
+            .into_iter()
+            .any(|direction| {
+                check_cheat(wall, direction, &time_matrix, walls)
+                    .is_some_and(|cheat| cheat >= limit)
+            });
+        if saves_enough {
+            map[wall] = 'X';
+        }
+    }
+    map[*start] = 'S';
+    map[*end] = 'E';
+    map.to_string()
+}
+
+fn calculate_time_of_race(walls: &Grid<bool>, start: Point) -> Grid<Option<u64>> {
+    let mut cost_matrix = Grid::new(walls.width(), walls.height(), None);
+    let times = search::distances(start, |position| {
+        walls
+            .neighbours4(*position)
+            .filter(|neighbour| !walls[*neighbour])
+    });
+    for (position, time) in times {
+        cost_matrix[position] = Some(time);
+    }
+    cost_matrix
+}
+
+fn calculate_possible_cheats(time_matrix: &Grid<Option<u64>>, walls: &Grid<bool>) -> Vec<u64> {
+    let mut cheats = Vec::new();
+    for (wall, _) in walls.iter().filter(|(_, is_wall)| **is_wall) {
+        if let Some(cheat) = check_cheat(wall, Direction::Up, time_matrix, walls) {
+            cheats.push(cheat);
+        }
+        if let Some(cheat) = check_cheat(wall, Direction::Left, time_matrix, walls) {
+            cheats.push(cheat);
+        }
+    }
+    cheats
+}
+
+/// Time saved by going through `wall` from the track on the `direction` side to the opposite one.
+/// Two sides the race reaches at about the same time give a cheat that saves nothing.
+fn check_cheat(
+    wall: Point,
+    direction: Direction,
+    time_matrix: &Grid<Option<u64>>,
+    walls: &Grid<bool>,
+) -> Option<u64> {
+    let side_1 = walls.step(wall, direction)?;
+    let side_2 = walls.step(wall, direction.opposite())?;
+    if !walls[side_1] && !walls[side_2] {
+        let cost_1 = time_matrix[side_1]?;
+        let cost_2 = time_matrix[side_2]?;
+        return Some(cost_1.abs_diff(cost_2).saturating_sub(2));
+    }
+    None
+}
+
+type Cheat = (Point, Point);
+
+fn calculate_possible_cheats_2(time_matrix: &Grid<Option<u64>>) -> Vec<u64> {
+    let mut cheats: HashMap<Cheat, u64> = HashMap::new();
+    for (position, cost) in time_matrix.iter() {
+        let Some(cost) = cost else {
+            continue;
+        };
+        for y in position.y.saturating_sub(20)..=position.y + 20 {
+            for x in position.x.saturating_sub(20)..=position.x + 20 {
+                let destination = Point::new(x, y);
+                let distance = position.manhattan_distance(destination) as u64;
+                if distance > 20 {
+                    continue;
+                }
+                if let Some(Some(t_cost)) = time_matrix.get(destination) {
+                    if cost + distance < *t_cost {
+                        cheats.insert((position, destination), t_cost - cost - distance);
+                    }
+                }
+            }
+        }
+    }
+    cheats.values().cloned().collect()
+}
+
+/// The walls, the start and the end of the race
+pub type Racetrack = (Grid<bool>, Point, Point);
+
+/// A racetrack of walls `#` and track `.` with a single start `S` and end `E`
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> Racetrack {
+    (
+        Grid::read(input, |c| "#.SE".contains(c).then_some(c == '#'), errors),
+        grid::find_single(input, 'S', "a start `S` in the racetrack", errors),
+        grid::find_single(input, 'E', "an end `E` in the racetrack", errors),
+    )
+}
@@ -0,0 +1,198 @@
+use common::{OrExpected, OrReport, ParseError, Puzzle, Result};
+use itertools::{iproduct, Itertools};
+use std::collections::HashMap;
+
+static NUMERIC_KEYPAD: [[char; 3]; 4] = [
+    ['7', '8', '9'],
+    ['4', '5', '6'],
+    ['1', '2', '3'],
+    ['#', '0', 'A'],
+];
+static NUMERIC_START: (usize, usize) = (3, 2);
+
+static DIRECTIONAL_KEYPAD: [[char; 3]; 2] = [['#', '^', 'A'], ['<', 'V', '>']];
+static DIRECTIONAL_START: (usize, usize) = (0, 2);
+
+pub struct Day21;
+
+/// Robots using a directional keypad between the person and the numeric keypad in the first part
+pub const PART1_ROBOTS: usize = 2;
+/// Robots using a directional keypad in the second part
+pub const PART2_ROBOTS: usize = 25;
+
+impl Puzzle for Day21 {
+    /// Each code with its numeric part
+    type Input = Vec<(String, u64)>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_codes(input, errors)
+    }
+
+    fn part1(codes: &Self::Input) -> u64 {
+        solution(codes, PART1_ROBOTS)
+    }
+
+    fn part2(codes: &Self::Input) -> u64 {
+        solution(codes, PART2_ROBOTS)
+    }
+}
+
+/// One code per line
+fn read_codes(input: &str, errors: &mut Vec<ParseError>) -> Vec<(String, u64)> {
+    input
+        .lines()
+        .filter(|c| !c.is_empty())
+        .filter_map(|code| read_code(input, code).or_report(errors))
+        .collect()
+}
+
+/// Three digits and `A`, like the codes on the door
+fn read_code(input: &str, code: &str) -> Result<(String, u64)> {
+    for (index, expected) in ["a digit", "a digit", "a digit", "`A`"]
+        .into_iter()
+        .enumerate()
+    {
+        // The buttons before are ASCII, so `index` starts a character
+        let rest = &code[index.min(code.len())..];
+        let button = rest.chars().next();
+        let fits = match index {
+            3 => button == Some('A'),
+            _ => button.is_some_and(|button| button.is_ascii_digit()),
+        };
+        if !fits {
+            return Err(ParseError::at(input, rest, expected));
+        }
+    }
+    if code.len() > 4 {
+        return Err(ParseError::at(
+            input,
+            &code[4..],
+            "the code to end after `A`",
+        ));
+    }
+    let n_code: u64 = code[..3]
+        .parse()
+        .or_expected(input, code, "a numeric code")?;
+    Ok((code.to_string(), n_code))
+}
+
+/// Sum of the complexities of the codes typed through `n_robots` directional keypads
+pub fn solution(codes: &[(String, u64)], n_robots: usize) -> u64 {
+    let mut result = 0;
+    for (code, n_code) in codes {
+        let code: Vec<char> = code.chars().collect();
+        let paths = find_path(code, &NUMERIC_KEYPAD, &NUMERIC_START);
+        let mut memoization = HashMap::new();
+        let n = paths
+            .into_iter()
+            .map(|path| handle_robot(path.into_iter().collect(), &mut memoization, n_robots))
+            .min()
+            .expect("Should find at least one solution");
+        result += n_code * n as u64;
+    }
+    result
+}
+
+fn handle_robot(
+    path: String,
+    memoization: &mut HashMap<(String, usize), usize>,
+    n_robot: usize,
+) -> usize {
+    let parts = path.split_inclusive('A');
+    let mut result = 0;
+    for part in parts {
+        if let Some(size) = memoization.get(&(part.to_string(), n_robot)) {
+            result += size;
+        } else {
+            let size = find_path(
+                part.chars().collect(),
+                &DIRECTIONAL_KEYPAD,
+                &DIRECTIONAL_START,
+            )
+            .into_iter()
+            .map(|path| {
+                if n_robot == 1 {
+                    path.len()
+                } else {
+                    handle_robot(path.into_iter().collect(), memoization, n_robot - 1)
+                }
+            })
+            .min()
+            .expect("Should find one solution at least");
+            memoization.insert((part.to_string(), n_robot), size);
+            result += size;
+        }
+    }
+    result
+}
+
+fn find_path(code: Vec<char>, keypad: &[[char; 3]], start: &(usize, usize)) -> Vec<Vec<char>> {
+    let mut result_paths = Vec::new();
+    let mut start = *start;
+    for button in code {
+        let result = inner_path_find(button, keypad, &start);
+        if result_paths.is_empty() {
+            result_paths = result.0;
+        } else {
+            result_paths = iproduct!(result_paths, result.0)
+                .map(|(a, b)| {
+                    let mut a = a.clone();
+                    a.extend(b);
+                    a
+                })
+                .collect();
+        }
+        start = result.1
+    }
+
+    result_paths
+}
+
+/// Every shortest sequence of moves from `start` to the `code` button, followed by its press
+fn inner_path_find(
+    code: char,
+    keypad: &[[char; 3]],
+    start: &(usize, usize),
+) -> (Vec<Vec<char>>, (usize, usize)) {
+    let button = |(row, column): (usize, usize)| {
+        keypad
+            .get(row)?
+            .get(column)
+            .filter(|button| **button != '#')
+    };
+    let paths = search::dijkstra_all(
+        *start,
+        |&(row, column)| {
+            [
+                (row.wrapping_sub(1), column),
+                (row + 1, column),
+                (row, column.wrapping_sub(1)),
+                (row, column + 1),
+            ]
+            .into_iter()
+            .filter(move |position| button(*position).is_some())
+            .map(|position| (position, 1))
+        },
+        |position| button(*position) == Some(&code),
+    )
+    .expect("Every button can be reached");
+    let moves = paths
+        .paths()
+        .into_iter()
+        .map(|path| {
+            path.iter()
+                .tuple_windows()
+                .map(|step| match step {
+                    ((row, _), (next_row, _)) if next_row < row => '^',
+                    ((row, _), (next_row, _)) if next_row > row => 'V',
+                    ((_, column), (_, next_column)) if next_column < column => '<',
+                    _ => '>',
+                })
+                .chain(['A'])
+                .collect()
+        })
+        .collect();
+    (moves, paths.goals[0])
+}
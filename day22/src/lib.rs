@@ -0,0 +1,104 @@
+use common::{OrExpected, OrReport, ParseError, Puzzle};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+pub struct Day22;
+
+/// New secret numbers of each buyer
+pub const ITERATIONS: usize = 2000;
+
+impl Puzzle for Day22 {
+    /// The initial secret number of each buyer
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1(secret_numbers: &Self::Input) -> u64 {
+        sum_of_secret_numbers(secret_numbers, ITERATIONS)
+    }
+
+    fn part2(secret_numbers: &Self::Input) -> u64 {
+        most_bananas(secret_numbers, ITERATIONS)
+    }
+}
+
+/// Sum of the secret number of each buyer after `iterations` new ones
+pub fn sum_of_secret_numbers(secret_numbers: &[u64], iterations: usize) -> u64 {
+    secret_numbers
+        .iter()
+        .map(|secret_number| {
+            let mut secret_number = *secret_number;
+            for _ in 0..iterations {
+                secret_number = calculate_next_secret_number(secret_number);
+            }
+            secret_number
+        })
+        .sum::<u64>()
+}
+
+/// Bananas bought with the best sequence of four price changes, over `iterations` prices
+pub fn most_bananas(secret_numbers: &[u64], iterations: usize) -> u64 {
+    let prices_and_diff_by_buyers: Vec<Vec<(u8, i8)>> = secret_numbers
+        .iter()
+        .map(|secret_number| calculate_prices_and_changes(*secret_number, iterations))
+        .collect();
+
+    let buyers_sequences = prices_and_diff_by_buyers
+        .into_iter()
+        .map(|prices_and_diff| {
+            prices_and_diff.into_iter().tuple_windows().fold(
+                HashMap::new(),
+                |mut acc, (a, b, c, d)| {
+                    acc.entry((a.1, b.1, c.1, d.1)).or_insert(d.0 as u64);
+                    acc
+                },
+            )
+        })
+        .fold(HashMap::new(), |mut acc, sequence_map| {
+            for (sequence, price) in sequence_map {
+                acc.entry(sequence)
+                    .and_modify(|acc_price| *acc_price += price)
+                    .or_insert(price);
+            }
+            acc
+        });
+    buyers_sequences.into_values().max().unwrap_or(0)
+}
+
+/// One secret number per line, and at least one
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> Vec<u64> {
+    let mut lines = input.lines().filter(|line| !line.is_empty()).peekable();
+    if lines.peek().is_none() {
+        errors.push(ParseError::end(input, "a secret number"));
+    }
+    lines
+        .filter_map(|line| {
+            line.parse()
+                .or_expected(input, line, "a secret number")
+                .or_report(errors)
+        })
+        .collect()
+}
+
+fn calculate_prices_and_changes(mut secret_number: u64, iterations: usize) -> Vec<(u8, i8)> {
+    let mut result = Vec::new();
+    for _ in 0..iterations {
+        let new_secret_number = calculate_next_secret_number(secret_number);
+        let price = new_secret_number % 10;
+        let diff = price as i8 - (secret_number % 10) as i8;
+        result.push((price as u8, diff));
+        secret_number = new_secret_number
+    }
+    result
+}
+
+fn calculate_next_secret_number(mut secret_number: u64) -> u64 {
+    secret_number = (secret_number ^ (secret_number << 6)) % 16777216;
+    secret_number = (secret_number ^ (secret_number >> 5)) % 16777216;
+    secret_number = (secret_number ^ (secret_number << 11)) % 16777216;
+    secret_number
+}
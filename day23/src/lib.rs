@@ -1,15 +1,29 @@
-use std::{collections::HashSet, fs::read_to_string, io::Result};
+use common::{OrExpected, OrReport, ParseError, Puzzle};
+use std::collections::HashSet;
 
-fn main() -> Result<()> {
-    println!("Part 1 example: {}", part_1("example.txt")?);
-    println!("Part 1 result: {}", part_1("input.txt")?);
-    println!("Part 2 example: {}", part_2("example.txt")?);
-    println!("Part 2 result: {}", part_2("input.txt")?);
-    Ok(())
+pub struct Day23;
+
+impl Puzzle for Day23 {
+    /// The adjacency matrix of the network and the sorted computer names
+    type Input = (Vec<Vec<u8>>, Vec<String>);
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1((adjacency_matrix, list_of_nodes): &Self::Input) -> usize {
+        part_1(adjacency_matrix, list_of_nodes)
+    }
+
+    fn part2((adjacency_matrix, list_of_nodes): &Self::Input) -> String {
+        part_2(adjacency_matrix, list_of_nodes)
+    }
 }
 
-fn part_1(file: &str) -> Result<usize> {
-    let (adjacency_matrix, list_of_nodes) = read_input(file)?;
+/// Sets of three connected computers where at least one name starts with `t`
+fn part_1(adjacency_matrix: &[Vec<u8>], list_of_nodes: &[String]) -> usize {
     let mut adj_with_node_with_t = list_of_nodes
         .iter()
         .enumerate()
@@ -38,11 +52,11 @@ fn part_1(file: &str) -> Result<usize> {
         .collect::<Vec<Vec<String>>>();
     adj_with_node_with_t.sort();
     adj_with_node_with_t.dedup();
-    Ok(adj_with_node_with_t.len())
+    adj_with_node_with_t.len()
 }
 
-fn part_2(file: &str) -> Result<String> {
-    let (adjacency_matrix, list_of_nodes) = read_input(file)?;
+/// Password of the LAN party, grown greedily from every computer
+fn part_2(adjacency_matrix: &[Vec<u8>], list_of_nodes: &[String]) -> String {
     let mut largest_clique: Option<Vec<(usize, String)>> = None;
     for (pos, node) in list_of_nodes.iter().enumerate() {
         let mut clique = vec![(pos, node.clone())];
@@ -61,20 +75,26 @@ fn part_2(file: &str) -> Result<String> {
             largest_clique = Some(clique);
         }
     }
-    Ok(largest_clique
+    largest_clique
         .unwrap()
         .into_iter()
         .map(|(_, node)| node)
         .collect::<Vec<String>>()
-        .join(","))
+        .join(",")
 }
 
-fn read_input(file: &str) -> Result<(Vec<Vec<u8>>, Vec<String>)> {
-    let data = read_to_string(file)?;
-    let pairs: Vec<(&str, &str)> = data
-        .split('\n')
-        .filter(|line| !line.is_empty())
-        .flat_map(|line| line.split_once('-'))
+/// One connection between two computers per line, and at least one
+fn read_input(input: &str, errors: &mut Vec<ParseError>) -> (Vec<Vec<u8>>, Vec<String>) {
+    let mut lines = input.lines().filter(|line| !line.is_empty()).peekable();
+    if lines.peek().is_none() {
+        errors.push(ParseError::end(input, "a connection between two computers"));
+    }
+    let pairs: Vec<(&str, &str)> = lines
+        .filter_map(|line| {
+            line.split_once('-')
+                .or_expected(input, line, "two computers separated by `-`")
+                .or_report(errors)
+        })
         .collect();
     let nodes: HashSet<&str> = pairs.iter().flat_map(|pair| [pair.0, pair.1]).collect();
     let mut nodes: Vec<String> = nodes.into_iter().map(|node| node.to_string()).collect();
@@ -92,5 +112,5 @@ fn read_input(file: &str) -> Result<(Vec<Vec<u8>>, Vec<String>)> {
         adjacency_matrix[node_b][node_b] = 1;
         adjacency_matrix[node_a][node_a] = 1;
     });
-    Ok((adjacency_matrix, nodes))
+    (adjacency_matrix, nodes)
 }
@@ -1,21 +1,30 @@
+use common::{split_at_empty_line, OrExpected, OrReport, ParseError, Puzzle, Result};
 use itertools::Itertools;
-use std::{
-    collections::HashMap,
-    fs::read_to_string,
-    io::{Error, Result},
-    u64,
-};
+use std::collections::HashMap;
 
-fn main() -> Result<()> {
-    println!("Part 1 example 1: {}", part_1("example_1.txt")?);
-    println!("Part 1 example 2: {}", part_1("example_2.txt")?);
-    println!("Part 1 input: {}", part_1("input.txt")?);
-    println!("Part 2 input: {}", part_2("input.txt")?);
-    Ok(())
+pub struct Day24;
+
+impl Puzzle for Day24 {
+    /// The gates of the device and the initial state of every wire
+    type Input = (Vec<LogicGate>, HashMap<Wire, WireState>);
+    type Answer1 = usize;
+    /// `None` when the device is not an adder with four pairs of swapped outputs like the real one
+    type Answer2 = Option<String>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_input(input, errors)
+    }
+
+    fn part1((gates, wires): &Self::Input) -> usize {
+        part_1(gates.clone(), wires.clone())
+    }
+
+    fn part2((gates, wires): &Self::Input) -> Option<String> {
+        part_2(gates.clone(), wires.clone())
+    }
 }
 
-fn part_1(file: &str) -> Result<usize> {
-    let (gates, wires) = read_input(file)?;
+fn part_1(gates: Vec<LogicGate>, wires: HashMap<Wire, WireState>) -> usize {
     let wires = execute(gates, wires);
     let mut z_wires: Vec<(Wire, WireState)> = wires
         .into_iter()
@@ -28,19 +37,25 @@ fn part_1(file: &str) -> Result<usize> {
             result |= 0x1 << i;
         }
     }
-    Ok(result)
+    result
 }
 
-fn part_2(file: &str) -> Result<String> {
-    let (gates, wires) = read_input(file)?;
-
+fn part_2(gates: Vec<LogicGate>, wires: HashMap<Wire, WireState>) -> Option<String> {
+    // The last output is the carry of the highest bits, so the numbers have one bit less
+    let bits = wires
+        .keys()
+        .filter_map(|wire| wire.0.strip_prefix('z')?.parse::<u32>().ok())
+        .max()
+        .filter(|bits| (1..64).contains(bits))?;
+    let last_output = Wire(format!("z{bits:02}"));
+    let all_ones = (1u64 << bits) - 1;
     // In a full adder, we should not have any gate different than a XOR connected to the output.
     // With the exception of the last output which is the carry
     let incorrect_last_gates = gates
         .iter()
         .filter(|gate| match gate {
             LogicGate::And(_, _, output) | LogicGate::Or(_, _, output) => {
-                output.0.starts_with('z') && output.0 != "z45"
+                output.0.starts_with('z') && *output != last_output
             }
             _ => false,
         })
@@ -59,14 +74,22 @@ fn part_2(file: &str) -> Result<String> {
         })
         .map(|gate| gate.output().clone())
         .collect_vec();
+    // Like in the real puzzle, three outputs are swapped with a carry
+    if incorrect_intermediary_gates.len() != 3 || incorrect_last_gates.len() != 3 {
+        return None;
+    }
     let mut correct_perm = Vec::new();
     let mut best_diff = u64::MAX;
+    let mut best_wrong_bits = u32::MAX;
     for perm in incorrect_intermediary_gates
         .iter()
         .chain(incorrect_last_gates.iter())
         .permutations(6)
         .filter(|perms| {
-            incorrect_intermediary_gates.contains(perms[0])
+            // The order of the pairs does not matter
+            perms[0] < perms[2]
+                && perms[2] < perms[4]
+                && incorrect_intermediary_gates.contains(perms[0])
                 && incorrect_last_gates.contains(perms[1])
                 && incorrect_intermediary_gates.contains(perms[2])
                 && incorrect_last_gates.contains(perms[3])
@@ -77,21 +100,27 @@ fn part_2(file: &str) -> Result<String> {
         let gates = swap_outputs(perm[0], perm[1], gates.clone());
         let gates = swap_outputs(perm[2], perm[3], gates);
         let gates = swap_outputs(perm[4], perm[5], gates);
-        let wires = set_input('x', 0x1FFFFFFFFFFF, wires.clone());
-        let wires = set_input('y', 0x1FFFFFFFFFFF, wires);
-        let result = calculate_result(&execute(gates, wires));
-        let diff = result ^ (0x1FFFFFFFFFFF + 0x1FFFFFFFFFFF);
+        let wires = set_input('x', all_ones, wires.clone());
+        let wires = set_input('y', all_ones, wires);
+        let result = calculate_result(&execute(gates.clone(), wires.clone()));
+        let diff = result ^ (all_ones + all_ones);
+        // Every bit adds the same with all of them set, so the single bits tell the pairs apart
+        let wrong_bits = wrong_bits(&gates, &wires, bits);
         // Loking the results by Eye. In this one we can clearly see that we have one carries with issue
-        if best_diff.count_ones() > diff.count_ones() {
+        if best_wrong_bits > wrong_bits {
+            best_wrong_bits = wrong_bits;
             best_diff = diff;
             correct_perm = perm;
         }
     }
+    if correct_perm.is_empty() {
+        return None;
+    }
     let gates = swap_outputs(correct_perm[0], correct_perm[1], gates.clone());
     let gates = swap_outputs(correct_perm[2], correct_perm[3], gates);
     let gates = swap_outputs(correct_perm[4], correct_perm[5], gates);
     let mut nodes_to_check = Vec::new();
-    for i in 0..=46 {
+    for i in 0..bits {
         if best_diff & (0x1 << i) > 0 {
             nodes_to_check.extend(list_of_nodes_from_output(
                 Wire(format!("z{:02}", i + 1)),
@@ -101,32 +130,37 @@ fn part_2(file: &str) -> Result<String> {
     }
     for perm in nodes_to_check.iter().permutations(2) {
         let gates = swap_outputs(perm[0], perm[1], gates.clone());
-        let wires = set_input('x', 0x1FFFFFFFFFFF, wires.clone());
-        let wires = set_input('y', 0x1FFFFFFFFFFF, wires.clone());
+        let wires = set_input('x', all_ones, wires.clone());
+        let wires = set_input('y', all_ones, wires.clone());
         let result = calculate_result(&execute(gates.clone(), wires.clone()));
-        if result == 0x1FFFFFFFFFFF + 0x1FFFFFFFFFFF {
-            let mut correct = true;
-            for i in 0..45 {
-                let value = 0x1 << i;
-                let wires = set_input('x', value, wires.clone());
-                let wires = set_input('y', 0, wires.clone());
-                let result = calculate_result(&execute(gates.clone(), wires.clone()));
-                if result != value {
-                    correct = false;
-                    break;
-                }
-            }
-            if correct {
-                correct_perm.extend(perm);
-                break;
-            }
+        if result == all_ones + all_ones && wrong_bits(&gates, &wires, bits) == 0 {
+            correct_perm.extend(perm);
+            correct_perm.sort();
+            return Some(
+                correct_perm
+                    .into_iter()
+                    .map(|wire| wire.0.clone())
+                    .join(","),
+            );
         }
     }
-    correct_perm.sort();
-    Ok(correct_perm
+    None
+}
+
+/// Bits of the sums that differ from the ones of an adder of `bits` bits, over the numbers with
+/// every bit set and the numbers with at most one bit set
+fn wrong_bits(gates: &[LogicGate], wires: &HashMap<Wire, WireState>, bits: u32) -> u32 {
+    let all_ones = (1u64 << bits) - 1;
+    let single_bits = (0..bits).flat_map(|i| [(1 << i, 0), (0, 1 << i), (1 << i, 1 << i)]);
+    [(all_ones, all_ones)]
         .into_iter()
-        .map(|wire| wire.0.clone())
-        .join(","))
+        .chain(single_bits)
+        .map(|(x, y)| {
+            let wires = set_input('x', x, wires.clone());
+            let wires = set_input('y', y, wires);
+            (calculate_result(&execute(gates.to_vec(), wires)) ^ (x + y)).count_ones()
+        })
+        .sum()
 }
 
 fn list_of_nodes_from_output(output: Wire, gates: &[LogicGate]) -> Vec<Wire> {
@@ -176,7 +210,7 @@ fn calculate_result(wires: &HashMap<Wire, WireState>) -> u64 {
         .iter()
         .filter(|(wire, _)| wire.0.starts_with('z'))
         .collect();
-    z_wires.sort_by(|a, b| a.0.cmp(&b.0));
+    z_wires.sort_by(|a, b| a.0.cmp(b.0));
     let mut result = 0;
     for (i, wire) in z_wires.iter().enumerate() {
         if *wire.1 == WireState::High {
@@ -193,8 +227,9 @@ fn set_input(
 ) -> HashMap<Wire, WireState> {
     wires.iter_mut().for_each(|(wire, wire_state)| {
         if let Some(n) = wire.0.strip_prefix(input) {
-            let n: usize = n.parse().expect("Should be a number");
-            if value & (0x1 << n) > 0 {
+            // Bits past the value, or wires named like inputs without a bit, stay low
+            let n: u32 = n.parse().unwrap_or(u32::MAX);
+            if value.checked_shr(n).unwrap_or(0) & 0x1 > 0 {
                 *wire_state = WireState::High;
             } else {
                 *wire_state = WireState::Low;
@@ -283,73 +318,101 @@ fn remove_available_gates(
         })
 }
 
-fn read_input(file: &str) -> Result<(Vec<LogicGate>, HashMap<Wire, WireState>)> {
-    let data = read_to_string(file)?;
-    let (wires, gates) = data.split_once("\n\n").ok_or_invalid_data()?;
+/// The initial wires, an empty line and one gate per line
+fn read_input(
+    input: &str,
+    errors: &mut Vec<ParseError>,
+) -> (Vec<LogicGate>, HashMap<Wire, WireState>) {
+    let Some((wires, gates)) = split_at_empty_line(input) else {
+        errors.push(ParseError::end(
+            input,
+            "an empty line between the wires and the gates",
+        ));
+        return (Vec::new(), HashMap::new());
+    };
     let mut wires = wires
-        .split('\n')
-        .map(|line| -> Result<(Wire, WireState)> {
-            let (wire, state) = line.split_once(':').ok_or_invalid_data()?;
-            Ok((
-                Wire(wire.to_string()),
-                if state.trim() == "1" {
-                    WireState::High
-                } else {
-                    WireState::Low
-                },
-            ))
-        })
-        .collect::<Result<HashMap<Wire, WireState>>>()?;
+        .lines()
+        .filter_map(|line| read_wire(input, line).or_report(errors))
+        .collect::<HashMap<Wire, WireState>>();
     let gates = gates
-        .split('\n')
+        .lines()
         .filter(|line| !line.is_empty())
-        .map(|line| {
-            let (gate, output) = line.split_once("->").ok_or_invalid_data()?;
-            let output = output.trim().to_string();
-            wires
-                .entry(Wire(output.clone()))
-                .or_insert(WireState::Impedance);
-            let (wire_a, rest) = gate.split_once(" ").ok_or_invalid_data()?;
-            let (gate, wire_b) = rest.split_once(" ").ok_or_invalid_data()?;
-            Ok(match gate.trim() {
-                "AND" => LogicGate::And(
-                    Wire(wire_a.trim().to_string()),
-                    Wire(wire_b.trim().to_string()),
-                    Wire(output),
-                ),
-                "OR" => LogicGate::Or(
-                    Wire(wire_a.trim().to_string()),
-                    Wire(wire_b.trim().to_string()),
-                    Wire(output),
-                ),
-                "XOR" => LogicGate::Xor(
-                    Wire(wire_a.trim().to_string()),
-                    Wire(wire_b.trim().to_string()),
-                    Wire(output),
-                ),
-                other => {
-                    return Err(Error::new(
-                        std::io::ErrorKind::InvalidData,
-                        format!("`{other}` is not a valid gate"),
-                    ))
-                }
-            })
-        })
-        .collect::<Result<Vec<LogicGate>>>()?;
-    Ok((gates, wires))
+        .filter_map(|line| read_gate(input, line, &mut wires).or_report(errors))
+        .collect::<Vec<_>>();
+    for (_, inputs) in gates.iter() {
+        for unknown in inputs
+            .iter()
+            .filter(|wire| !wires.contains_key(&Wire(wire.to_string())))
+        {
+            errors.push(ParseError::at(input, unknown, "a known wire"));
+        }
+    }
+    (gates.into_iter().map(|(gate, _)| gate).collect(), wires)
+}
+
+fn read_wire(input: &str, line: &str) -> Result<(Wire, WireState)> {
+    let (wire, state) = line
+        .split_once(':')
+        .or_expected(input, line, "a wire followed by `:`")?;
+    let state = state.trim();
+    Ok((
+        Wire(wire.to_string()),
+        match state {
+            "1" => WireState::High,
+            "0" => WireState::Low,
+            _ => return Err(ParseError::at(input, state, "`0` or `1`")),
+        },
+    ))
 }
+
+/// The gate of `line` with its two input wires. Its output wire joins `wires`, with no state yet.
+fn read_gate<'a>(
+    input: &str,
+    line: &'a str,
+    wires: &mut HashMap<Wire, WireState>,
+) -> Result<(LogicGate, [&'a str; 2])> {
+    let (gate, output) =
+        line.split_once("->")
+            .or_expected(input, line, "a gate followed by `->`")?;
+    let output = output.trim().to_string();
+    wires
+        .entry(Wire(output.clone()))
+        .or_insert(WireState::Impedance);
+    let (wire_a, rest) = gate
+        .split_once(" ")
+        .or_expected(input, gate, "two wires and a gate")?;
+    let (gate, wire_b) = rest
+        .split_once(" ")
+        .or_expected(input, rest, "a gate and a wire")?;
+    let (wire_a, wire_b) = (wire_a.trim(), wire_b.trim());
+    let gate = match gate.trim() {
+        "AND" => LogicGate::And,
+        "OR" => LogicGate::Or,
+        "XOR" => LogicGate::Xor,
+        _ => return Err(ParseError::at(input, gate, "`AND`, `OR` or `XOR`")),
+    };
+    Ok((
+        gate(
+            Wire(wire_a.to_string()),
+            Wire(wire_b.to_string()),
+            Wire(output),
+        ),
+        [wire_a, wire_b],
+    ))
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum WireState {
+pub enum WireState {
     High,
     Low,
     Impedance,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Wire(String);
+pub struct Wire(String);
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-enum LogicGate {
+pub enum LogicGate {
     Or(Wire, Wire, Wire),
     And(Wire, Wire, Wire),
     Xor(Wire, Wire, Wire),
@@ -372,13 +435,3 @@ impl LogicGate {
         }
     }
 }
-
-trait OkOrInvalidData<T> {
-    fn ok_or_invalid_data(self) -> Result<T>;
-}
-
-impl<T> OkOrInvalidData<T> for Option<T> {
-    fn ok_or_invalid_data(self) -> Result<T> {
-        self.ok_or(Error::new(std::io::ErrorKind::InvalidData, "Invalid data"))
-    }
-}
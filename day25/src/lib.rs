@@ -0,0 +1,103 @@
+use common::{sections, ParseError, Puzzle};
+
+pub struct Day25;
+
+impl Puzzle for Day25 {
+    type Input = LocksAndKeys;
+    type Answer1 = usize;
+    /// Day 25 only has one puzzle, the second star comes for free.
+    type Answer2 = &'static str;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_schematics(input, errors)
+    }
+
+    fn part1((locks, keys): &Self::Input) -> usize {
+        part_1(locks, keys)
+    }
+
+    fn part2(_: &Self::Input) -> &'static str {
+        "Merry Christmas!"
+    }
+}
+
+/// Lock and key pairs whose pins do not overlap in any column
+fn part_1(locks: &[Vec<usize>], keys: &[Vec<usize>]) -> usize {
+    let mut matches = 0;
+    for lock in locks.iter() {
+        for key in keys.iter() {
+            if lock.iter().zip(key.iter()).all(|(l, k)| l + k <= 5) {
+                matches += 1;
+            }
+        }
+    }
+    matches
+}
+
+pub type LocksAndKeys = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+/// Schematics of seven rows of five `#` or `.`, separated by an empty line. Locks have their top
+/// row filled and keys their bottom one.
+fn read_schematics(input: &str, errors: &mut Vec<ParseError>) -> LocksAndKeys {
+    let mut locks = Vec::new();
+    let mut keys = Vec::new();
+    for part in sections(input) {
+        let lines = part.lines().collect::<Vec<&str>>();
+        let errors_before = errors.len();
+        for line in lines.iter() {
+            for (index, _) in line.char_indices().filter(|(_, c)| !"#.".contains(*c)) {
+                errors.push(ParseError::at(input, &line[index..], "`#` or `.`"));
+            }
+            match line.char_indices().nth(5) {
+                Some((extra, _)) => errors.push(ParseError::at(
+                    input,
+                    &line[extra..],
+                    "the row to end after 5 tiles",
+                )),
+                None if line.chars().count() < 5 => errors.push(ParseError::at(
+                    input,
+                    &line[line.len()..],
+                    "5 tiles in the row",
+                )),
+                None => {}
+            }
+        }
+        match lines.get(7) {
+            Some(extra) => errors.push(ParseError::at(
+                input,
+                extra,
+                "an empty line after a schematic seven rows tall",
+            )),
+            None if lines.len() < 7 => errors.push(ParseError::at(
+                input,
+                &part[part.trim_end().len()..],
+                "a schematic seven rows tall",
+            )),
+            None => {}
+        }
+        if errors.len() > errors_before {
+            continue;
+        }
+        let pins = |rows: std::ops::Range<usize>| -> Vec<usize> {
+            (0..5)
+                .map(|column| {
+                    rows.clone()
+                        .filter(|line| lines[*line].chars().nth(column) == Some('#'))
+                        .count()
+                })
+                .collect()
+        };
+        if lines[0] == "#####" && lines[6] == "....." {
+            locks.push(pins(1..7));
+        } else if lines[0] == "....." && lines[6] == "#####" {
+            keys.push(pins(0..6));
+        } else {
+            errors.push(ParseError::at(
+                input,
+                lines[0],
+                "`#####` on the top row of a lock or the bottom row of a key",
+            ));
+        }
+    }
+    (locks, keys)
+}
@@ -1,55 +1,59 @@
-use std::fs::read_to_string;
+use common::{ParseError, Puzzle};
 use std::iter::Peekable;
 use std::str::Chars;
 
-fn main() -> std::io::Result<()> {
-    part_1("input.txt")?;
-    part_2("input.txt")?;
-    Ok(())
-}
+pub struct Day3;
 
-fn part_1(file_name: &str) -> std::io::Result<()> {
-    let input = read_to_string(file_name)?;
-    let mut tokenizer = Tokenizer::new(&input).peekable();
-    let mut result = 0;
-    while let Some(token) = tokenizer.next() {
-        if token == Token::Mul {
-            if tokenizer.next_if_eq(&Token::LeftParen).is_none() {
-                continue;
-            }
-            let Some(Token::Number(a)) = tokenizer.next_if(|t| matches!(t, Token::Number(_)))
-            else {
-                continue;
-            };
-            if tokenizer.next_if_eq(&Token::Comma).is_none() {
-                continue;
-            }
-            let Some(Token::Number(b)) = tokenizer.next_if(|t| matches!(t, Token::Number(_)))
-            else {
-                continue;
-            };
-            if tokenizer.next_if_eq(&Token::RightParen).is_none() {
-                continue;
+impl Puzzle for Day3 {
+    /// The corrupted memory, read token by token by each part
+    type Input = String;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    /// Corrupted memory can hold anything, the parts skip what is not an instruction.
+    fn read(input: &str, _: &mut Vec<ParseError>) -> Self::Input {
+        input.to_string()
+    }
+
+    fn part1(input: &Self::Input) -> i64 {
+        let mut tokenizer = Tokenizer::new(input).peekable();
+        let mut result = 0;
+        while let Some(token) = tokenizer.next() {
+            if token == Token::Mul {
+                if tokenizer.next_if_eq(&Token::LeftParen).is_none() {
+                    continue;
+                }
+                let Some(Token::Number(a)) = tokenizer.next_if(|t| matches!(t, Token::Number(_)))
+                else {
+                    continue;
+                };
+                if tokenizer.next_if_eq(&Token::Comma).is_none() {
+                    continue;
+                }
+                let Some(Token::Number(b)) = tokenizer.next_if(|t| matches!(t, Token::Number(_)))
+                else {
+                    continue;
+                };
+                if tokenizer.next_if_eq(&Token::RightParen).is_none() {
+                    continue;
+                }
+                result += a * b;
             }
-            result += a * b;
         }
+        result
     }
-    println!("Result {result}");
-    Ok(())
-}
 
-fn part_2(file_name: &str) -> std::io::Result<()> {
-    let input = read_to_string(file_name)?;
-    let parser = Parser::new(&input);
-    let mut result = 0;
-    for ast in parser {
-        match ast {
-            Ast::Mul(a, b) => result += a * b,
-            Ast::Do => {}
+    fn part2(input: &Self::Input) -> i64 {
+        let parser = Parser::new(input);
+        let mut result = 0;
+        for ast in parser {
+            match ast {
+                Ast::Mul(a, b) => result += a * b,
+                Ast::Do => {}
+            }
         }
+        result
     }
-    println!("Result {result}");
-    Ok(())
 }
 
 #[derive(Debug, PartialEq)]
@@ -118,10 +122,7 @@ impl Iterator for Parser<'_> {
                         continue;
                     }
                     self.processing_dont = true;
-                    loop {
-                        let Some(ast) = self.next() else {
-                            break;
-                        };
+                    for ast in self.by_ref() {
                         if Ast::Do == ast {
                             break;
                         }
@@ -207,9 +208,13 @@ impl Iterator for Tokenizer<'_> {
                     while let Some(c) = self.chars.next_if(|c| c.is_ascii_digit()) {
                         result_as_string.push(c);
                     }
-                    return Some(Token::Number(
-                        result_as_string.parse().expect("Error parsing number"),
-                    ));
+                    // Corrupted memory may hold numbers too big to ever be an instruction
+                    return Some(
+                        result_as_string
+                            .parse()
+                            .map(Token::Number)
+                            .unwrap_or(Token::Ignored),
+                    );
                 }
                 _ => return Some(Token::Ignored),
             }
@@ -0,0 +1,65 @@
+use common::{ParseError, Puzzle};
+use grid::{Grid, Point, NEIGHBOURS_8};
+
+pub struct Day4;
+
+impl Puzzle for Day4 {
+    type Input = Grid<char>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        Grid::read(input, Some, errors)
+    }
+
+    fn part1(lines: &Self::Input) -> i64 {
+        let mut n_xmas = 0;
+        for position in lines.points() {
+            n_xmas += count_xmas(position, lines);
+        }
+        n_xmas
+    }
+
+    fn part2(lines: &Self::Input) -> i64 {
+        let mut n_xmas = 0;
+        for position in lines.points() {
+            if is_x_mas(position, lines) {
+                n_xmas += 1;
+            }
+        }
+        n_xmas
+    }
+}
+
+fn count_xmas(position: Point, lines: &Grid<char>) -> i64 {
+    NEIGHBOURS_8
+        .into_iter()
+        .filter(|direction| {
+            lines
+                .ray(position, *direction)
+                .take(4)
+                .copied()
+                .eq("XMAS".chars())
+        })
+        .count() as i64
+}
+
+fn is_x_mas(position: Point, lines: &Grid<char>) -> bool {
+    if lines[position] != 'A' {
+        return false;
+    }
+    let corner = |dx, dy| {
+        position
+            .offset(dx, dy)
+            .and_then(|corner| lines.get(corner))
+            .copied()
+    };
+    is_mas([corner(-1, -1), corner(1, 1)]) && is_mas([corner(1, -1), corner(-1, 1)])
+}
+
+fn is_mas(diagonal_ends: [Option<char>; 2]) -> bool {
+    matches!(
+        diagonal_ends,
+        [Some('M'), Some('S')] | [Some('S'), Some('M')]
+    )
+}
@@ -1,59 +1,39 @@
-use std::{
-    collections::HashMap,
-    fs::File,
-    io::{BufRead, BufReader, Error, ErrorKind, Result},
-};
+use common::{OrExpected, OrReport, ParseError, Puzzle, Result};
+use std::collections::HashMap;
 
-fn main() -> std::io::Result<()> {
-    println!("Part 1 : {}", part1("input.txt")?);
-    println!("Part 2 : {}", part2("input.txt")?);
-    Ok(())
-}
+pub struct Day5;
 
-fn part1(file: &str) -> std::io::Result<i64> {
-    let (rules, updates) = load_rules_and_updates(file)?;
-    let (correct_updates, _) = split_correct_and_incorrect_updates(updates, &rules);
+impl Puzzle for Day5 {
+    /// The pages that must come after each page, and the updates
+    type Input = RulesAndUpdate;
+    type Answer1 = i64;
+    type Answer2 = i64;
 
-    Ok(sum_middle_pages(correct_updates))
-}
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        load_rules_and_updates(input, errors)
+    }
 
-fn part2(file: &str) -> std::io::Result<i64> {
-    let (rules, updates) = load_rules_and_updates(file)?;
-    let (_, incorrect_updates) = split_correct_and_incorrect_updates(updates, &rules);
-    let fixed_updates = fix_updates(incorrect_updates, &rules);
+    fn part1((rules, updates): &Self::Input) -> i64 {
+        let (correct_updates, _) = split_correct_and_incorrect_updates(updates.clone(), rules);
+        sum_middle_pages(correct_updates)
+    }
 
-    Ok(sum_middle_pages(fixed_updates))
+    fn part2((rules, updates): &Self::Input) -> i64 {
+        let (_, incorrect_updates) = split_correct_and_incorrect_updates(updates.clone(), rules);
+        let fixed_updates = fix_updates(incorrect_updates, rules);
+        sum_middle_pages(fixed_updates)
+    }
 }
 
-type RulesAndUpdate = (HashMap<i64, Vec<i64>>, Vec<Vec<i64>>);
-fn load_rules_and_updates(file: &str) -> Result<RulesAndUpdate> {
-    let buf_read = BufReader::new(File::open(file)?);
-    let (rules, updates): (Vec<_>, Vec<_>) = buf_read
+pub type RulesAndUpdate = (HashMap<i64, Vec<i64>>, Vec<Vec<i64>>);
+fn load_rules_and_updates(input: &str, errors: &mut Vec<ParseError>) -> RulesAndUpdate {
+    let (rules, updates): (Vec<_>, Vec<_>) = input
         .lines()
-        .filter(|line| line.as_ref().map(|line| !line.is_empty()).unwrap_or(false))
-        .partition(|line| {
-            line.as_ref()
-                .map(|line| line.contains('|'))
-                .unwrap_or(false)
-        });
+        .filter(|line| !line.is_empty())
+        .partition(|line| line.contains('|'));
     let rules: HashMap<i64, Vec<i64>> = rules
         .into_iter()
-        .map(|rule| {
-            let rule = rule?;
-            let Some((part1, part2)) = rule.split_once('|') else {
-                return Err(Error::new(ErrorKind::InvalidData, ""));
-            };
-            Ok((
-                part1
-                    .parse()
-                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?,
-                part2
-                    .parse()
-                    .map_err(|err| Error::new(ErrorKind::InvalidData, err))?,
-            ))
-        })
-        .collect::<Result<Vec<(i64, i64)>>>()?
-        .into_iter()
+        .filter_map(|rule| read_rule(input, rule).or_report(errors))
         .fold(HashMap::new(), |mut acc, (key, value)| {
             acc.entry(key).or_default().push(value);
             acc
@@ -61,17 +41,27 @@ fn load_rules_and_updates(file: &str) -> Result<RulesAndUpdate> {
     let updates = updates
         .into_iter()
         .map(|line| {
-            line?
-                .split(',')
-                .map(|split| {
+            line.split(',')
+                .filter_map(|split| {
                     split
                         .parse()
-                        .map_err(|err| Error::new(ErrorKind::InvalidData, err))
+                        .or_expected(input, split, "a page number")
+                        .or_report(errors)
                 })
-                .collect::<Result<Vec<i64>>>()
+                .collect()
         })
-        .collect::<Result<Vec<Vec<i64>>>>()?;
-    Ok((rules, updates))
+        .collect();
+    (rules, updates)
+}
+
+fn read_rule(input: &str, rule: &str) -> Result<(i64, i64)> {
+    let (part1, part2) = rule
+        .split_once('|')
+        .or_expected(input, rule, "a rule `X|Y`")?;
+    Ok((
+        part1.parse().or_expected(input, part1, "a page number")?,
+        part2.parse().or_expected(input, part2, "a page number")?,
+    ))
 }
 
 fn split_correct_and_incorrect_updates(
@@ -0,0 +1,104 @@
+use common::{ParseError, Puzzle};
+use grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+pub struct Day6;
+
+pub struct Lab {
+    map: Grid<char>,
+    guard: Guard,
+}
+
+impl Puzzle for Day6 {
+    type Input = Lab;
+    /// No answer when the guard never leaves the lab
+    type Answer1 = Option<i64>;
+    type Answer2 = Option<i64>;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_lab(input, errors)
+    }
+
+    fn part1(lab: &Self::Input) -> Option<i64> {
+        Some(walk_and_return_postions(lab.guard.clone(), &lab.map)?.len() as i64)
+    }
+
+    fn part2(lab: &Self::Input) -> Option<i64> {
+        let mut map = lab.map.clone();
+        let initial_guard = lab.guard.clone();
+        let mut guard_path = walk_and_return_postions(initial_guard.clone(), &map)?;
+        guard_path.remove(&initial_guard.position);
+        let mut found_loops = 0;
+        for position in guard_path {
+            map[position] = '#';
+            if walk_and_return_postions(initial_guard.clone(), &map).is_none() {
+                found_loops += 1;
+            }
+            map[position] = '.';
+        }
+        Some(found_loops)
+    }
+}
+
+/// The lab with an `X` on every position the guard visits before leaving it, and no path when
+/// the guard never leaves
+pub fn draw_path(lab: &Lab) -> String {
+    let mut map = lab.map.clone();
+    let path = walk_and_return_postions(lab.guard.clone(), &lab.map);
+    for position in path.unwrap_or_default() {
+        map[position] = 'X';
+    }
+    map.to_string()
+}
+
+/// A map of `.` and obstructions `#` with a single guard `^`
+fn read_lab(input: &str, errors: &mut Vec<ParseError>) -> Lab {
+    let map = Grid::read(input, |c| "#.^".contains(c).then_some(c), errors);
+    let guard = Guard {
+        position: grid::find_single(input, '^', "a guard `^` in the map", errors),
+        direction: Direction::Up,
+    };
+    Lab { map, guard }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Guard {
+    position: Point,
+    direction: Direction,
+}
+
+impl Guard {
+    /// Moves one tile forward, turning right while facing an obstacle. A guard with obstacles on
+    /// every side stays where it is. Returns `false` when the guard walks out of the map.
+    pub fn walk(&mut self, map: &Grid<char>) -> bool {
+        for _ in 0..4 {
+            let Some(next_position) = map.step(self.position, self.direction) else {
+                return false;
+            };
+            if map[next_position] != '#' {
+                self.position = next_position;
+                return true;
+            }
+            self.direction = self.direction.turn_right();
+        }
+        true
+    }
+}
+
+/// Every position of the guard until it leaves the map, or `None` when it walks in a loop
+fn walk_and_return_postions(mut guard: Guard, map: &Grid<char>) -> Option<HashSet<Point>> {
+    let mut list_of_guard_states: HashSet<Guard> = HashSet::new();
+    loop {
+        if !list_of_guard_states.insert(guard.clone()) {
+            return None;
+        }
+        if !guard.walk(map) {
+            return Some(
+                list_of_guard_states
+                    .into_iter()
+                    .map(|g| g.position)
+                    .collect(),
+            );
+        }
+    }
+}
@@ -1,62 +1,62 @@
+use common::{OrExpected, OrReport, ParseError, Puzzle, Result};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufRead, BufReader, Error, ErrorKind, Result};
 
-fn main() -> std::io::Result<()> {
-    println!("Part 1 : {}", part1("input.txt")?);
-    println!("Part 2 : {}", part2("input.txt")?);
-    Ok(())
-}
+pub struct Day7;
 
-fn part1(file: &str) -> std::io::Result<u64> {
-    let equations = read_equations(file)?;
-    let mut operators_bag = BagOfOperatorCombinations::new(vec![Operators::Add, Operators::Mul]);
-    let sum_of_valid = equations
-        .into_iter()
-        .filter(|equation| validate_equation(equation, &mut operators_bag))
-        .map(|equation| equation.result)
-        .sum();
+impl Puzzle for Day7 {
+    type Input = Vec<Equation>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    Ok(sum_of_valid)
-}
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_equations(input, errors)
+    }
+
+    fn part1(equations: &Self::Input) -> u64 {
+        let mut operators_bag =
+            BagOfOperatorCombinations::new(vec![Operators::Add, Operators::Mul]);
+        equations
+            .iter()
+            .filter(|equation| validate_equation(equation, &mut operators_bag))
+            .map(|equation| equation.result)
+            .sum()
+    }
 
-fn part2(file: &str) -> std::io::Result<u64> {
-    let equations = read_equations(file)?;
-    let mut operators_bag = BagOfOperatorCombinations::new(vec![
-        Operators::Add,
-        Operators::Mul,
-        Operators::Concatenate,
-    ]);
-    let sum_of_valid = equations
-        .into_iter()
-        .filter(|equation| validate_equation(equation, &mut operators_bag))
-        .map(|equation| equation.result)
-        .sum();
-    Ok(sum_of_valid)
+    fn part2(equations: &Self::Input) -> u64 {
+        let mut operators_bag = BagOfOperatorCombinations::new(vec![
+            Operators::Add,
+            Operators::Mul,
+            Operators::Concatenate,
+        ]);
+        equations
+            .iter()
+            .filter(|equation| validate_equation(equation, &mut operators_bag))
+            .map(|equation| equation.result)
+            .sum()
+    }
 }
 
-fn read_equations(file: &str) -> Result<Vec<Equation>> {
-    let buf_read = BufReader::new(File::open(file)?);
-    buf_read
+/// One equation per line
+fn read_equations(input: &str, errors: &mut Vec<ParseError>) -> Vec<Equation> {
+    input
         .lines()
-        .map(|line| {
-            let line = line?;
-            let Some((result, numbers)) = line.split_once(':') else {
-                return Err(Error::new(ErrorKind::InvalidData, "Not found result"));
-            };
-            let numbers = numbers
-                .trim()
-                .split(" ")
-                .map(|n| n.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e)))
-                .collect::<Result<Vec<u64>>>()?;
-            Ok(Equation {
-                result: result
-                    .parse()
-                    .map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
-                numbers,
-            })
-        })
-        .collect::<Result<Vec<Equation>>>()
+        .filter_map(|line| read_equation(input, line).or_report(errors))
+        .collect()
+}
+
+fn read_equation(input: &str, line: &str) -> Result<Equation> {
+    let (result, numbers) =
+        line.split_once(':')
+            .or_expected(input, line, "a test value followed by `:`")?;
+    let numbers = numbers
+        .trim()
+        .split(" ")
+        .map(|n| n.parse().or_expected(input, n, "a number"))
+        .collect::<Result<Vec<u64>>>()?;
+    Ok(Equation {
+        result: result.parse().or_expected(input, result, "a test value")?,
+        numbers,
+    })
 }
 
 fn validate_equation(equation: &Equation, operators_bag: &mut BagOfOperatorCombinations) -> bool {
@@ -92,7 +92,7 @@ fn validate_equation(equation: &Equation, operators_bag: &mut BagOfOperatorCombi
 }
 
 #[derive(Debug)]
-struct Equation {
+pub struct Equation {
     result: u64,
     numbers: Vec<u64>,
 }
@@ -0,0 +1,120 @@
+use common::{ParseError, Puzzle};
+use grid::Grid;
+use std::collections::{HashMap, HashSet};
+use std::ops::Div;
+
+pub struct Day8;
+
+pub struct Map {
+    x_size: usize,
+    y_size: usize,
+    antennas_by_frequency: HashMap<char, Vec<Antenna>>,
+}
+
+impl Puzzle for Day8 {
+    type Input = Map;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_map(input, errors)
+    }
+
+    fn part1(map: &Self::Input) -> usize {
+        let set_of_antinodes = find_antinodes(map, |x, y, antenna_1, antenna_2| {
+            let y_diff_1 = y as i32 - antenna_1.y as i32;
+            let x_diff_1 = x as i32 - antenna_1.x as i32;
+            let y_diff_2 = y as i32 - antenna_2.y as i32;
+            let x_diff_2 = x as i32 - antenna_2.x as i32;
+            if (y_diff_1 as f32).div(x_diff_1 as f32) != (y_diff_2 as f32).div(x_diff_2 as f32) {
+                return false;
+            }
+            let dist_1 = x_diff_1.pow(2) + y_diff_1.pow(2);
+            let dist_2 = x_diff_2.pow(2) + y_diff_2.pow(2);
+            if dist_1 * 4 == dist_2 || dist_2 * 4 == dist_1 {
+                return true;
+            }
+            false
+        });
+        set_of_antinodes.len()
+    }
+
+    fn part2(map: &Self::Input) -> usize {
+        let set_of_antinodes = find_antinodes(map, |x, y, antenna_1, antenna_2| {
+            let y_diff_1 = y as i32 - antenna_1.y as i32;
+            let x_diff_1 = x as i32 - antenna_1.x as i32;
+            let y_diff_2 = y as i32 - antenna_2.y as i32;
+            let x_diff_2 = x as i32 - antenna_2.x as i32;
+            if (y_diff_1 == 0 && x_diff_1 == 0) || (y_diff_2 == 0 && x_diff_2 == 0) {
+                return true;
+            }
+            if (y_diff_1 as f32).div(x_diff_1 as f32) != (y_diff_2 as f32).div(x_diff_2 as f32) {
+                return false;
+            }
+            true
+        });
+        set_of_antinodes.len()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Antenna {
+    x: usize,
+    y: usize,
+    frequency: char,
+}
+
+/// A free tile, or an antenna whose frequency is a letter or a digit
+fn tile(c: char) -> Option<Option<char>> {
+    match c {
+        '.' => Some(None),
+        c if c.is_ascii_alphanumeric() => Some(Some(c)),
+        _ => None,
+    }
+}
+
+fn read_map(input: &str, errors: &mut Vec<ParseError>) -> Map {
+    let map = Grid::read(input, tile, errors);
+    let mut antennas_by_frequency = HashMap::new();
+    for (position, frequency) in map.iter() {
+        if let Some(frequency) = *frequency {
+            antennas_by_frequency
+                .entry(frequency)
+                .or_insert(Vec::new())
+                .push(Antenna {
+                    x: position.x,
+                    y: position.y,
+                    frequency,
+                });
+        }
+    }
+    Map {
+        x_size: map.width(),
+        y_size: map.height(),
+        antennas_by_frequency,
+    }
+}
+
+fn find_antinodes(
+    map: &Map,
+    checker: impl Fn(usize, usize, &Antenna, &Antenna) -> bool,
+) -> HashSet<(usize, usize)> {
+    let mut set_of_antinodes = HashSet::new();
+    for y in 0..map.y_size {
+        for x in 0..map.x_size {
+            for (_, antennas) in map.antennas_by_frequency.iter() {
+                for (i, antenna_1) in antennas.iter().enumerate() {
+                    for antenna_2 in antennas.iter().skip(i + 1) {
+                        if antenna_1 == antenna_2 {
+                            continue;
+                        }
+                        if checker(x, y, antenna_1, antenna_2) {
+                            set_of_antinodes.insert((x, y));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    set_of_antinodes
+}
@@ -1,17 +1,27 @@
-use std::{
-    fs::read_to_string,
-    io::{Error, ErrorKind, Result},
-    iter::repeat,
-};
+use common::{OrExpected, OrReport, ParseError, Puzzle};
 
-fn main() -> Result<()> {
-    println!("Part 1 : {}", part1("input.txt")?);
-    println!("Part 2 : {}", part2("input.txt")?);
-    Ok(())
+pub struct Day9;
+
+impl Puzzle for Day9 {
+    /// Blocks of the disk, holding the file id or `-1` when free
+    type Input = Vec<i32>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn read(input: &str, errors: &mut Vec<ParseError>) -> Self::Input {
+        read_and_unpack_disck(input, errors)
+    }
+
+    fn part1(disk: &Self::Input) -> i64 {
+        part1(disk.clone())
+    }
+
+    fn part2(disk: &Self::Input) -> i64 {
+        part2(disk.clone())
+    }
 }
 
-fn part1(file: &str) -> Result<i64> {
-    let mut unpacked_disk = read_and_unpack_disck(file)?;
+fn part1(mut unpacked_disk: Vec<i32>) -> i64 {
     for i in 0..unpacked_disk.len() {
         if unpacked_disk[i] == -1 {
             let Some((pos, block)) = unpacked_disk
@@ -32,12 +42,11 @@ fn part1(file: &str) -> Result<i64> {
         }
     }
 
-    Ok(checksum(unpacked_disk))
+    checksum(unpacked_disk)
 }
 
-fn part2(file: &str) -> Result<i64> {
-    let mut unpacked_disk = read_and_unpack_disck(file)?;
-    let mut i = (unpacked_disk.len() - 1) as isize;
+fn part2(mut unpacked_disk: Vec<i32>) -> i64 {
+    let mut i = unpacked_disk.len() as isize - 1;
     while i >= 0 {
         if unpacked_disk[i as usize] != -1 {
             let file_id = unpacked_disk[i as usize];
@@ -48,14 +57,11 @@ fn part2(file: &str) -> Result<i64> {
                 .count();
             i -= file_size as isize - 1;
             let mut start_cursor_to_find_free_space = 0;
-            loop {
-                let Some((start_free_space, _)) = unpacked_disk[start_cursor_to_find_free_space..]
-                    .iter()
-                    .enumerate()
-                    .find(|(_, element)| **element == -1)
-                else {
-                    break;
-                };
+            while let Some((start_free_space, _)) = unpacked_disk[start_cursor_to_find_free_space..]
+                .iter()
+                .enumerate()
+                .find(|(_, element)| **element == -1)
+            {
                 let start_free_space = start_cursor_to_find_free_space + start_free_space;
                 let Some((free_space_size, _)) = unpacked_disk[start_free_space..]
                     .iter()
@@ -80,26 +86,28 @@ fn part2(file: &str) -> Result<i64> {
         }
         i -= 1
     }
-    Ok(checksum(unpacked_disk))
+    checksum(unpacked_disk)
 }
 
-fn read_and_unpack_disck(file: &str) -> Result<Vec<i32>> {
-    let data = read_to_string(file)?;
+/// The digits of the first line, unpacked into file ids and `-1` for free space
+fn read_and_unpack_disck(input: &str, errors: &mut Vec<ParseError>) -> Vec<i32> {
     let mut unpacked_disk: Vec<i32> = Vec::new();
-    for (pos, c) in data.chars().enumerate() {
-        if c == '\n' {
-            break;
-        }
-        let Some(c_as_number) = c.to_digit(10) else {
-            return Err(Error::new(ErrorKind::InvalidData, "Not a valid number"));
+    let disk_map = input.lines().next().unwrap_or_default();
+    for (pos, c) in disk_map.char_indices() {
+        let Some(c_as_number) = c
+            .to_digit(10)
+            .or_expected(input, &disk_map[pos..], "a digit")
+            .or_report(errors)
+        else {
+            continue;
         };
         if pos % 2 == 0 {
-            unpacked_disk.extend(repeat((pos / 2) as i32).take(c_as_number as usize));
+            unpacked_disk.extend(std::iter::repeat_n((pos / 2) as i32, c_as_number as usize));
         } else {
-            unpacked_disk.extend(repeat(-1).take(c_as_number as usize));
+            unpacked_disk.extend(std::iter::repeat_n(-1, c_as_number as usize));
         }
     }
-    Ok(unpacked_disk)
+    unpacked_disk
 }
 
 fn checksum(disk: Vec<i32>) -> i64 {
@@ -0,0 +1,15 @@
+use crate::{lines, Rng};
+
+/// `size` pairs of five digit locations. Some locations of the right list are taken from the left
+/// one, so the similarity score is not zero.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<u32> = (0..size).map(|_| rng.u32(10000..100000)).collect();
+    lines(left.iter().map(|location| {
+        let right = if rng.u8(0..4) == 0 {
+            left[rng.usize(..left.len())]
+        } else {
+            rng.u32(10000..100000)
+        };
+        format!("{location}   {right}")
+    }))
+}
@@ -0,0 +1,27 @@
+use crate::Rng;
+use grid::Grid;
+
+/// A square topographic map with `size` tiles per side. Heights mostly differ by one from the
+/// tile on the left or above, so there are long hiking trails.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, 0u8);
+    for point in map.points() {
+        let neighbour = match rng.bool() {
+            true => point.offset(-1, 0),
+            false => point.offset(0, -1),
+        };
+        map[point] = match neighbour {
+            Some(neighbour) if rng.u8(0..4) > 0 => {
+                let height = map[neighbour];
+                if (rng.bool() || height == 0) && height < 9 {
+                    height + 1
+                } else {
+                    height - 1
+                }
+            }
+            _ => rng.u8(0..10),
+        };
+    }
+    map.render(|height| char::from(b'0' + height))
+}
@@ -0,0 +1,12 @@
+use crate::Rng;
+
+/// `size` engraved stones of up to seven digits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..size.max(1))
+        .map(|_| match rng.u8(0..4) {
+            0 => rng.u32(0..10).to_string(),
+            _ => rng.u32(0..10_000_000).to_string(),
+        })
+        .collect();
+    stones.join(" ") + "\n"
+}
@@ -0,0 +1,20 @@
+use crate::Rng;
+use grid::Grid;
+
+/// A square garden with `size` plots per side. Plots often copy the plant of the plot on the left
+/// or above, so regions grow into odd shapes, with holes and regions inside others.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut garden = Grid::new(size, size, 'A');
+    for point in garden.points() {
+        let neighbour = match rng.bool() {
+            true => point.offset(-1, 0),
+            false => point.offset(0, -1),
+        };
+        garden[point] = match neighbour {
+            Some(neighbour) if rng.u8(0..10) > 1 => garden[neighbour],
+            _ => rng.uppercase(),
+        };
+    }
+    garden.to_string()
+}
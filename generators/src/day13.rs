@@ -0,0 +1,31 @@
+use crate::Rng;
+
+/// `size` claw machines. Half of the prizes can be won with at most 100 presses of each button.
+/// The buttons never move the claw in the same direction, so there is at most one way to win.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let machines: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let (a, b) = loop {
+                let a = (rng.i64(10..100), rng.i64(10..100));
+                let b = (rng.i64(10..100), rng.i64(10..100));
+                if a.0 * b.1 != a.1 * b.0 {
+                    break (a, b);
+                }
+            };
+            let prize = if rng.bool() {
+                let (presses_a, presses_b) = (rng.i64(0..=100), rng.i64(0..=100));
+                (
+                    presses_a * a.0 + presses_b * b.0,
+                    presses_a * a.1 + presses_b * b.1,
+                )
+            } else {
+                (rng.i64(1000..20000), rng.i64(1000..20000))
+            };
+            format!(
+                "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
@@ -0,0 +1,14 @@
+use crate::{lines, Rng};
+
+/// `size` robots somewhere in the 101x103 bathroom, with velocities of up to 100 tiles per second.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        format!(
+            "p={},{} v={},{}",
+            rng.u8(0..101),
+            rng.u8(0..103),
+            rng.i8(-100..=100),
+            rng.i8(-100..=100)
+        )
+    }))
+}
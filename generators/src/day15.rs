@@ -0,0 +1,27 @@
+use crate::Rng;
+use grid::{Direction, Grid, Point};
+
+/// A square warehouse with `size` tiles per side, surrounded by walls, full of boxes and with a
+/// few walls inside, followed by `size * size` moves of the robot.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+    let mut warehouse = Grid::new(size, size, '#');
+    for y in 1..size - 1 {
+        for x in 1..size - 1 {
+            warehouse[Point::new(x, y)] = match rng.u8(0..100) {
+                0..5 => '#',
+                5..30 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    warehouse[Point::new(rng.usize(1..size - 1), rng.usize(1..size - 1))] = '@';
+    let moves: Vec<char> = (0..size * size)
+        .map(|_| Direction::ALL[rng.usize(..4)].to_arrow())
+        .collect();
+    let moves: Vec<String> = moves
+        .chunks(70)
+        .map(|line| line.iter().collect::<String>() + "\n")
+        .collect();
+    warehouse.to_string() + "\n" + &moves.concat()
+}
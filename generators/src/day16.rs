@@ -0,0 +1,21 @@
+use crate::{maze, Rng};
+use grid::Point;
+
+/// A maze of `size` by `size` cells with the reindeer in the bottom left corner and the end in
+/// the top right one, at least two cells wide so they differ. Some walls are knocked down so there
+/// are several best paths.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut maze = maze::carve(rng, size.max(2));
+    let side = maze.width();
+    for y in 1..side - 1 {
+        for x in 1..side - 1 {
+            if (x + y) % 2 == 1 && rng.u8(0..10) == 0 {
+                maze[Point::new(x, y)] = false;
+            }
+        }
+    }
+    let mut map = maze.map(|wall| if *wall { '#' } else { '.' });
+    map[Point::new(1, side - 2)] = 'S';
+    map[Point::new(side - 2, 1)] = 'E';
+    map.to_string()
+}
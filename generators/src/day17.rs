@@ -0,0 +1,78 @@
+use crate::Rng;
+
+/// A program of `size` numbers shaped like the real ones: a loop that reads the low bits of register
+/// A, mixes them with `bxl`, `cdv B` and `bxc`, drops them with `adv 3` and prints one octal digit,
+/// and that has a value of register A making it output itself. That value has one octal digit per
+/// number, so programs have from 16 to 20 numbers for it to fit in 63 bits.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let instructions = size.clamp(16, 20) / 2;
+    loop {
+        let program = program(rng, instructions - 3);
+        if quine(&program, 0, program.len()).is_some() {
+            let program: Vec<String> = program.iter().map(u64::to_string).collect();
+            return format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+                rng.u64(1..1 << 40),
+                program.join(",")
+            );
+        }
+    }
+}
+
+/// `bst A`, `mixing` instructions with one `adv 3` among them, `out B` and `jnz 0`. C is only read
+/// once a `cdv B` of the same loop has set it, and B is below 8 whenever `cdv B` shifts A by it.
+fn program(rng: &mut Rng, mixing: usize) -> Vec<u64> {
+    let adv = rng.usize(0..mixing);
+    let mut program = vec![2, 4];
+    let (mut c_set, mut c_read) = (false, false);
+    for i in 0..mixing {
+        let instruction = if i == adv {
+            [0, 3]
+        } else {
+            match rng.u8(0..3) {
+                0 if !c_read => {
+                    c_set = true;
+                    [7, 5]
+                }
+                1 if c_set => {
+                    c_read = true;
+                    [4, rng.u64(0..8)]
+                }
+                _ => [1, rng.u64(0..8)],
+            }
+        };
+        program.extend(instruction);
+    }
+    program.extend([5, 5, 3, 0]);
+    program
+}
+
+/// Builds register A three bits at a time, from the last output to the first one.
+fn quine(program: &[u64], register_a: u64, remaining: usize) -> Option<u64> {
+    if remaining == 0 {
+        return Some(register_a);
+    }
+    (0..8).find_map(|digit| {
+        let register_a = register_a.checked_mul(8)? + digit;
+        (register_a != 0 && output(program, register_a) == program[remaining - 1])
+            .then(|| quine(program, register_a, remaining - 1))
+            .flatten()
+    })
+}
+
+/// First value printed by the program, running its loop once.
+fn output(program: &[u64], register_a: u64) -> u64 {
+    let (mut a, mut b, mut c) = (register_a, 0, 0);
+    for instruction in program.chunks(2) {
+        match instruction {
+            [0, 3] => a >>= 3,
+            [1, operand] => b ^= operand,
+            [2, 4] => b = a % 8,
+            [4, _] => b ^= c,
+            [5, 5] => return b % 8,
+            [7, 5] => c = a >> b,
+            _ => unreachable!("Not an instruction of the generated programs"),
+        }
+    }
+    unreachable!("The generated programs print a value")
+}
@@ -0,0 +1,67 @@
+use crate::{lines, Rng};
+use grid::Point;
+use std::collections::HashSet;
+
+/// Bytes falling in a `size` by `size` memory space, about two thirds of it like the real puzzle
+/// with its 71x71 space. The first quarter of the space keeps a path to the exit open, 1024 bytes
+/// and more at the real size, and a later byte cuts it.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_with_answer(rng, size).0
+}
+
+/// The side of the memory space of the inputs of `size`.
+pub fn side(size: usize) -> usize {
+    size.max(3)
+}
+
+/// The input with the coordinates of the first byte cutting the exit off, like the answer.
+///
+/// A random path going only right and down stays open until the last byte of one anti-diagonal
+/// falls on it. Every step changes `x + y` by one, so no path crosses a full anti-diagonal.
+pub fn generate_with_answer(rng: &mut Rng, size: usize) -> (String, String) {
+    let side = side(size);
+    let exit = Point::new(side - 1, side - 1);
+    let mut path = vec![Point::new(0, 0)];
+    let mut position = Point::new(0, 0);
+    while position != exit {
+        if position.y == side - 1 || (position.x < side - 1 && rng.bool()) {
+            position.x += 1;
+        } else {
+            position.y += 1;
+        }
+        path.push(position);
+    }
+    let on_path: HashSet<Point> = path.iter().copied().collect();
+    let cut = rng.usize(1..2 * (side - 1));
+    let blocking = *path
+        .iter()
+        .find(|byte| byte.x + byte.y == cut)
+        .expect("The path crosses every anti-diagonal");
+    let (mut diagonal, mut others): (Vec<Point>, Vec<Point>) = (0..side * side)
+        .map(|i| Point::new(i % side, i / side))
+        .filter(|byte| !on_path.contains(byte))
+        .partition(|byte| byte.x + byte.y == cut);
+    rng.shuffle(&mut others);
+    let before = rng
+        .usize(side * side / 4..=side * side * 3 / 5)
+        .clamp(diagonal.len(), diagonal.len() + others.len());
+    let mut others_after = others.split_off(before - diagonal.len());
+    let mut bytes = others;
+    bytes.append(&mut diagonal);
+    rng.shuffle(&mut bytes);
+    bytes.push(blocking);
+    // A few more bytes fall once the exit is cut off, on the path too
+    others_after.extend(
+        path.into_iter()
+            .filter(|byte| *byte != blocking && *byte != Point::new(0, 0) && *byte != exit),
+    );
+    rng.shuffle(&mut others_after);
+    let after = rng.usize(0..=others_after.len() / 4);
+    bytes.extend(others_after.into_iter().take(after));
+    let input = lines(
+        bytes
+            .into_iter()
+            .map(|byte| format!("{},{}", byte.x, byte.y)),
+    );
+    (input, format!("{},{}", blocking.x, blocking.y))
+}
@@ -0,0 +1,39 @@
+use crate::{lines, Rng};
+
+const STRIPES: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+/// Towels of one to eight stripes, then `size` designs. Most designs are made of towels and the
+/// others have a single stripe that no towel starts with at that point.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let missing = STRIPES[rng.usize(..STRIPES.len())];
+    let mut towels: Vec<String> = STRIPES
+        .iter()
+        .filter(|stripe| **stripe != missing)
+        .map(|stripe| stripe.to_string())
+        .collect();
+    while towels.len() < 40 {
+        let len = rng.usize(2..=8);
+        let towel = stripes(rng, len);
+        if !towels.contains(&towel) {
+            towels.push(towel);
+        }
+    }
+    rng.shuffle(&mut towels);
+    let designs = (0..size).map(|_| {
+        let mut design = String::new();
+        while design.len() < 20 {
+            design += &towels[rng.usize(..towels.len())];
+        }
+        if rng.u8(0..4) == 0 {
+            design.push(missing);
+        }
+        design
+    });
+    towels.join(", ") + "\n\n" + &lines(designs)
+}
+
+fn stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len)
+        .map(|_| STRIPES[rng.usize(..STRIPES.len())])
+        .collect()
+}
@@ -0,0 +1,30 @@
+use crate::{lines, Rng};
+
+/// `size` reports of five to eight levels. About half of them are safe, and some of the others
+/// are safe once one level is removed.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let increasing = rng.bool();
+        let mut level = rng.i32(20..80);
+        let mut levels: Vec<i32> = (0..rng.usize(5..=8))
+            .map(|_| {
+                let step = rng.i32(1..=3);
+                level += if increasing { step } else { -step };
+                level
+            })
+            .collect();
+        match rng.u8(0..4) {
+            0 => {
+                let index = rng.usize(..levels.len());
+                levels[index] += rng.i32(-4..=4);
+            }
+            1 => rng.shuffle(&mut levels),
+            _ => {}
+        }
+        levels
+            .iter()
+            .map(|level| level.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }))
+}
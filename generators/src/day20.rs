@@ -0,0 +1,39 @@
+use crate::{maze, Rng};
+use grid::{Grid, Point};
+
+/// A single racetrack winding through a maze of `size` by `size` cells, at least two so the track
+/// does not end where it starts: the longest path from a random start, everything else being a
+/// wall.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let maze = maze::carve(rng, size.max(2));
+    let cells = (maze.width() - 1) / 2;
+    let start = Point::new(rng.usize(..cells) * 2 + 1, rng.usize(..cells) * 2 + 1);
+    let mut previous = Grid::new(maze.width(), maze.height(), None);
+    let mut queue = vec![start];
+    let mut end = start;
+    previous[start] = Some(start);
+    while !queue.is_empty() {
+        end = queue[0];
+        queue = queue
+            .into_iter()
+            .flat_map(|tile| maze.neighbours4(tile).map(move |next| (tile, next)))
+            .filter(|(_, next)| !maze[*next])
+            .filter_map(|(tile, next)| match previous[next] {
+                Some(_) => None,
+                None => {
+                    previous[next] = Some(tile);
+                    Some(next)
+                }
+            })
+            .collect();
+    }
+    let mut map = Grid::new(maze.width(), maze.height(), '#');
+    let mut tile = end;
+    while tile != start {
+        map[tile] = '.';
+        tile = previous[tile].expect("Every tile of the maze is reachable");
+    }
+    map[start] = 'S';
+    map[end] = 'E';
+    map.to_string()
+}
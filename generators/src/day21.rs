@@ -0,0 +1,6 @@
+use crate::{lines, Rng};
+
+/// `size` door codes of three digits followed by `A`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| format!("{:03}A", rng.u16(0..1000))))
+}
@@ -0,0 +1,6 @@
+use crate::{lines, Rng};
+
+/// The initial secret number of `size` buyers.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| rng.u32(1..16777216).to_string()))
+}
@@ -0,0 +1,38 @@
+use crate::{lines, Rng};
+use std::collections::BTreeSet;
+
+/// Connections between `size` computers (at most 676, as names have two letters). Each computer
+/// is connected to a few others, and one LAN party is larger than any other group.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut names: Vec<String> = (b'a'..=b'z')
+        .flat_map(|a| (b'a'..=b'z').map(move |b| format!("{}{}", a as char, b as char)))
+        .collect();
+    rng.shuffle(&mut names);
+    names.truncate(size.clamp(4, names.len()));
+    let mut connections = BTreeSet::new();
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            connections.insert((a.min(b), a.max(b)));
+        }
+    };
+    for computer in 0..names.len() {
+        for _ in 0..rng.usize(1..=3) {
+            connect(computer, rng.usize(..names.len()));
+        }
+    }
+    let party = (names.len() / 4).clamp(4, 13);
+    for a in 0..party {
+        for b in a + 1..party {
+            connect(a, b);
+        }
+    }
+    let mut connections: Vec<String> = connections
+        .into_iter()
+        .map(|(a, b)| match rng.bool() {
+            true => format!("{}-{}", names[a], names[b]),
+            false => format!("{}-{}", names[b], names[a]),
+        })
+        .collect();
+    rng.shuffle(&mut connections);
+    lines(connections)
+}
@@ -0,0 +1,94 @@
+use crate::{lines, Rng};
+use std::collections::HashSet;
+
+/// A ripple-carry adder of two `size` bit numbers with random initial wires. Like in the real
+/// puzzle, four pairs of gates in four different bits have their output wires swapped: three
+/// output bits with a carry gate, and one sum with a carry of the inputs.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_with_answer(rng, size).0
+}
+
+/// The input with the eight swapped wires, sorted and separated by `,` like the answer.
+pub fn generate_with_answer(rng: &mut Rng, size: usize) -> (String, String) {
+    let bits = size.max(10);
+    let mut names = HashSet::new();
+    let mut name = || loop {
+        let name: String = (0..3).map(|_| rng.char('a'..='w')).collect();
+        if names.insert(name.clone()) {
+            return name;
+        }
+    };
+    let mut gates = Vec::new();
+    // Output wires of each bit: `[x XOR y, x AND y, sum AND carry, carry out]`
+    let mut wires = Vec::new();
+    let mut carry = name();
+    gates.push((
+        "x00".to_string(),
+        "XOR",
+        "y00".to_string(),
+        "z00".to_string(),
+    ));
+    gates.push(("x00".to_string(), "AND", "y00".to_string(), carry.clone()));
+    for bit in 1..bits {
+        let (x, y, z) = (
+            format!("x{bit:02}"),
+            format!("y{bit:02}"),
+            format!("z{bit:02}"),
+        );
+        let (sum, inputs_carry, sum_carry) = (name(), name(), name());
+        let carry_out = match bit + 1 == bits {
+            true => format!("z{bits:02}"),
+            false => name(),
+        };
+        gates.push((x.clone(), "XOR", y.clone(), sum.clone()));
+        gates.push((x, "AND", y, inputs_carry.clone()));
+        gates.push((sum.clone(), "XOR", carry.clone(), z));
+        gates.push((sum.clone(), "AND", carry, sum_carry.clone()));
+        gates.push((
+            inputs_carry.clone(),
+            "OR",
+            sum_carry.clone(),
+            carry_out.clone(),
+        ));
+        wires.push([sum, inputs_carry, sum_carry, carry_out.clone()]);
+        carry = carry_out;
+    }
+    // Every other bit, so that no two swaps touch the same carry
+    let mut swapped_bits: Vec<usize> = (2..bits - 1).step_by(2).collect();
+    assert!(swapped_bits.len() >= 4, "Four bits to swap wires in");
+    rng.shuffle(&mut swapped_bits);
+    let mut swapped = Vec::new();
+    for (swap, bit) in swapped_bits.into_iter().take(4).enumerate() {
+        let [sum, inputs_carry, sum_carry, carry_out] = &wires[bit - 1];
+        let (a, b) = match swap {
+            0 => (sum.clone(), inputs_carry.clone()),
+            _ => {
+                let carries = [inputs_carry, sum_carry, carry_out];
+                (format!("z{bit:02}"), carries[rng.usize(..3)].clone())
+            }
+        };
+        for gate in gates.iter_mut() {
+            if gate.3 == a {
+                gate.3 = b.clone();
+            } else if gate.3 == b {
+                gate.3 = a.clone();
+            }
+        }
+        swapped.extend([a, b]);
+    }
+    swapped.sort();
+    let inputs = ['x', 'y'].into_iter().flat_map(|operand| {
+        (0..bits)
+            .map(|bit| format!("{operand}{bit:02}: {}", rng.u8(0..2)))
+            .collect::<Vec<_>>()
+    });
+    let inputs = lines(inputs);
+    rng.shuffle(&mut gates);
+    let gates = gates
+        .into_iter()
+        .map(|(a, gate, b, output)| match rng.bool() {
+            true => format!("{a} {gate} {b} -> {output}"),
+            false => format!("{b} {gate} {a} -> {output}"),
+        });
+    (inputs + "\n" + &lines(gates), swapped.join(","))
+}
@@ -0,0 +1,31 @@
+use crate::Rng;
+
+/// `size` schematics of locks and keys, seven rows of five pins each.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let schematics: Vec<String> = (0..size.max(1))
+        .map(|_| {
+            let heights: Vec<usize> = (0..5).map(|_| rng.usize(0..=5)).collect();
+            let is_lock = rng.bool();
+            (0..7)
+                .map(|row| {
+                    let row: String = heights
+                        .iter()
+                        .map(|height| {
+                            let filled = match is_lock {
+                                true => row <= *height,
+                                false => row >= 6 - height,
+                            };
+                            if filled {
+                                '#'
+                            } else {
+                                '.'
+                            }
+                        })
+                        .collect();
+                    row + "\n"
+                })
+                .collect()
+        })
+        .collect();
+    schematics.join("\n")
+}
@@ -0,0 +1,59 @@
+use crate::Rng;
+
+const NOISE: &[&str] = &[
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul(6,9!",
+    "?(12,34)",
+    "don't",
+    "do",
+    "mul[3,7]",
+    "from()",
+    "select()",
+    "when()",
+    "what()",
+    "who()",
+    "'",
+    "]",
+    "#",
+    "%",
+    "&",
+    "@",
+    "^",
+    "$",
+    "+",
+    "<",
+    ">",
+    " ",
+    "{",
+    ",",
+    ":",
+    ";",
+    "/",
+    "why()",
+    "mul(1,2,3)",
+    "mul(1234,5)",
+    "xmul(2,",
+];
+
+/// Corrupted memory with `size` valid `mul` instructions, `do()` and `don't()` between them and
+/// noise everywhere, including broken instructions.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut memory = String::new();
+    for _ in 0..size {
+        for _ in 0..rng.usize(0..6) {
+            memory += NOISE[rng.usize(..NOISE.len())];
+        }
+        match rng.u8(0..10) {
+            0 => memory += "do()",
+            1 => memory += "don't()",
+            _ => {}
+        }
+        memory += &format!("mul({},{})", rng.u16(1..1000), rng.u16(1..1000));
+        if rng.u8(0..20) == 0 {
+            memory.push('\n');
+        }
+    }
+    memory.push('\n');
+    memory
+}
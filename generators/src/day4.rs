@@ -0,0 +1,11 @@
+use crate::Rng;
+use grid::Grid;
+
+/// A square word search of `X`, `M`, `A` and `S` with `size` letters per side.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let letters = ['X', 'M', 'A', 'S'];
+    Grid::new(size, size, ())
+        .map(|_| letters[rng.usize(..letters.len())])
+        .to_string()
+}
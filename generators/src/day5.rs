@@ -0,0 +1,30 @@
+use crate::{lines, Rng};
+
+/// Ordering rules for every pair of 49 pages, then `size` updates of an odd number of pages.
+/// Half of the updates are already in the right order.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pages: Vec<u8> = (10..100).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let mut rules = Vec::new();
+    for (index, before) in pages.iter().enumerate() {
+        for after in &pages[index + 1..] {
+            rules.push(format!("{before}|{after}"));
+        }
+    }
+    rng.shuffle(&mut rules);
+    let updates = (0..size).map(|_| {
+        let mut update: Vec<usize> = (0..pages.len()).collect();
+        rng.shuffle(&mut update);
+        update.truncate(rng.usize(2..12) * 2 + 1);
+        if rng.bool() {
+            update.sort();
+        }
+        update
+            .iter()
+            .map(|index| pages[*index].to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    lines(rules) + "\n" + &lines(updates)
+}
@@ -0,0 +1,33 @@
+use crate::Rng;
+use grid::{Direction, Grid, Point};
+use std::collections::HashSet;
+
+/// A square lab with `size` tiles per side, some obstructions and a guard facing up. Labs where
+/// the guard would never walk out are thrown away.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(2);
+    loop {
+        let mut lab = Grid::new(size, size, ()).map(|_| if rng.u8(0..100) < 6 { '#' } else { '.' });
+        let guard = Point::new(rng.usize(..size), rng.usize(..size));
+        lab[guard] = '^';
+        if walks_out(&lab, guard) {
+            return lab.to_string();
+        }
+    }
+}
+
+fn walks_out(lab: &Grid<char>, mut position: Point) -> bool {
+    let mut direction = Direction::Up;
+    let mut visited = HashSet::new();
+    while visited.insert((position, direction)) {
+        let Some(next) = lab.step(position, direction) else {
+            return true;
+        };
+        if lab[next] == '#' {
+            direction = direction.turn_right();
+        } else {
+            position = next;
+        }
+    }
+    false
+}
@@ -0,0 +1,22 @@
+use crate::{lines, Rng};
+
+/// `size` equations of two to eight numbers. Half of the test values come from combining the
+/// numbers with random operators, so they can be calibrated.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    lines((0..size).map(|_| {
+        let numbers: Vec<u64> = (0..rng.usize(2..=8)).map(|_| rng.u64(1..100)).collect();
+        let mut result = numbers[0];
+        for number in &numbers[1..] {
+            result = match rng.u8(0..3) {
+                0 => result + number,
+                1 => result * number,
+                _ => result * 10u64.pow(number.ilog10() + 1) + number,
+            };
+        }
+        if rng.bool() {
+            result += rng.u64(1..10);
+        }
+        let numbers: Vec<String> = numbers.iter().map(|n| n.to_string()).collect();
+        format!("{result}: {}", numbers.join(" "))
+    }))
+}
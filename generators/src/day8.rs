@@ -0,0 +1,17 @@
+use crate::Rng;
+use grid::{Grid, Point};
+
+const FREQUENCIES: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// A square map with `size` tiles per side and a few antennas of each of a few frequencies.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut map = Grid::new(size, size, '.');
+    for _ in 0..(size / 2).max(1) {
+        let frequency = FREQUENCIES[rng.usize(..FREQUENCIES.len())] as char;
+        for _ in 0..rng.usize(2..=4) {
+            map[Point::new(rng.usize(..size), rng.usize(..size))] = frequency;
+        }
+    }
+    map.to_string()
+}
@@ -0,0 +1,14 @@
+use crate::Rng;
+
+/// A disk map of `size` files of one to nine blocks, with up to nine free blocks between them.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut disk = String::new();
+    for file in 0..size {
+        if file > 0 {
+            disk.push(rng.digit(10));
+        }
+        disk.push(char::from(b'0' + rng.u8(1..10)));
+    }
+    disk.push('\n');
+    disk
+}
@@ -0,0 +1,245 @@
+//! Random puzzle inputs for every day, for stress tests, property tests and benchmarks.
+//!
+//! A generator only writes inputs that hold the guarantees of the real puzzle (the guard walks out
+//! of the lab, the maze has a way out, the netlist is an adder with four swapped pairs...), so the
+//! solvers are expected to answer all of them, but for the known failures listed by the generator
+//! tests of `aoc`. The same seed always gives the same input.
+
+pub use fastrand::Rng;
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+mod maze;
+
+pub struct Generator {
+    pub day: u8,
+    /// What `size` counts, e.g. "lines" or "side of the map".
+    pub unit: &'static str,
+    /// A size close to the one of the real puzzle input.
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+pub fn get(day: u8) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+/// Generates the input of `day` from `seed`.
+pub fn generate(day: u8, seed: u64, size: usize) -> Option<String> {
+    let generator = get(day)?;
+    Some((generator.generate)(&mut Rng::with_seed(seed), size))
+}
+
+/// The same input as [`generate`] with the answer of the part it is built around, for the days
+/// whose generator knows it: the first byte cutting the exit off on day 18, part 2, and the
+/// swapped wires on day 24, part 2.
+pub fn generate_with_answer(day: u8, seed: u64, size: usize) -> Option<(String, u8, String)> {
+    let rng = &mut Rng::with_seed(seed);
+    let (input, answer) = match day {
+        18 => day18::generate_with_answer(rng, size),
+        24 => day24::generate_with_answer(rng, size),
+        _ => return None,
+    };
+    Some((input, 2, answer))
+}
+
+/// The side of the day 18 memory space filled by the inputs of `size`, whose puzzle constants
+/// depend on it.
+pub fn day18_side(size: usize) -> usize {
+    day18::side(size)
+}
+
+pub static GENERATORS: [Generator; 25] = [
+    Generator {
+        day: 1,
+        unit: "pairs of locations",
+        default_size: 1000,
+        generate: day1::generate,
+    },
+    Generator {
+        day: 2,
+        unit: "reports",
+        default_size: 1000,
+        generate: day2::generate,
+    },
+    Generator {
+        day: 3,
+        unit: "`mul` instructions",
+        default_size: 700,
+        generate: day3::generate,
+    },
+    Generator {
+        day: 4,
+        unit: "side of the word search",
+        default_size: 140,
+        generate: day4::generate,
+    },
+    Generator {
+        day: 5,
+        unit: "updates",
+        default_size: 200,
+        generate: day5::generate,
+    },
+    Generator {
+        day: 6,
+        unit: "side of the lab",
+        default_size: 130,
+        generate: day6::generate,
+    },
+    Generator {
+        day: 7,
+        unit: "equations",
+        default_size: 850,
+        generate: day7::generate,
+    },
+    Generator {
+        day: 8,
+        unit: "side of the map",
+        default_size: 50,
+        generate: day8::generate,
+    },
+    Generator {
+        day: 9,
+        unit: "files",
+        default_size: 10000,
+        generate: day9::generate,
+    },
+    Generator {
+        day: 10,
+        unit: "side of the map",
+        default_size: 50,
+        generate: day10::generate,
+    },
+    Generator {
+        day: 11,
+        unit: "stones",
+        default_size: 8,
+        generate: day11::generate,
+    },
+    Generator {
+        day: 12,
+        unit: "side of the garden",
+        default_size: 140,
+        generate: day12::generate,
+    },
+    Generator {
+        day: 13,
+        unit: "claw machines",
+        default_size: 320,
+        generate: day13::generate,
+    },
+    Generator {
+        day: 14,
+        unit: "robots",
+        default_size: 500,
+        generate: day14::generate,
+    },
+    Generator {
+        day: 15,
+        unit: "side of the warehouse",
+        default_size: 50,
+        generate: day15::generate,
+    },
+    Generator {
+        day: 16,
+        unit: "maze cells per side",
+        default_size: 70,
+        generate: day16::generate,
+    },
+    Generator {
+        day: 17,
+        unit: "numbers in the program, from 16 to 20",
+        default_size: 16,
+        generate: day17::generate,
+    },
+    Generator {
+        day: 18,
+        unit: "side of the memory space",
+        default_size: 71,
+        generate: day18::generate,
+    },
+    Generator {
+        day: 19,
+        unit: "designs",
+        default_size: 400,
+        generate: day19::generate,
+    },
+    Generator {
+        day: 20,
+        unit: "maze cells per side",
+        default_size: 70,
+        generate: day20::generate,
+    },
+    Generator {
+        day: 21,
+        unit: "codes",
+        default_size: 5,
+        generate: day21::generate,
+    },
+    Generator {
+        day: 22,
+        unit: "buyers",
+        default_size: 2000,
+        generate: day22::generate,
+    },
+    Generator {
+        day: 23,
+        unit: "computers",
+        default_size: 520,
+        generate: day23::generate,
+    },
+    Generator {
+        day: 24,
+        unit: "bits of each operand, at least 10",
+        default_size: 45,
+        generate: day24::generate,
+    },
+    Generator {
+        day: 25,
+        unit: "locks and keys",
+        default_size: 500,
+        generate: day25::generate,
+    },
+];
+
+/// Lines joined with `\n`, the last one included.
+fn lines(lines: impl IntoIterator<Item = String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_input() {
+        for generator in GENERATORS.iter() {
+            let day = generator.day;
+            assert_eq!(generate(day, 7, 10), generate(day, 7, 10), "day {day}");
+            assert_ne!(generate(day, 7, 10), generate(day, 8, 10), "day {day}");
+        }
+    }
+}
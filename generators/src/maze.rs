@@ -0,0 +1,32 @@
+use crate::Rng;
+use grid::{Direction, Grid, Point};
+
+/// A perfect maze of `cells` by `cells` cells, `true` being a wall. Cells sit on odd coordinates
+/// and there is exactly one path between any two of them.
+pub fn carve(rng: &mut Rng, cells: usize) -> Grid<bool> {
+    let side = cells.max(1) * 2 + 1;
+    let mut maze = Grid::new(side, side, true);
+    let start = Point::new(1, 1);
+    maze[start] = false;
+    let mut stack = vec![start];
+    while let Some(&cell) = stack.last() {
+        let mut directions = Direction::ALL;
+        rng.shuffle(&mut directions);
+        let next = directions.into_iter().find_map(|direction| {
+            let wall = maze.step(cell, direction)?;
+            let next = maze.step(wall, direction)?;
+            maze[next].then_some((wall, next))
+        });
+        match next {
+            Some((wall, next)) => {
+                maze[wall] = false;
+                maze[next] = false;
+                stack.push(next);
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+    maze
+}
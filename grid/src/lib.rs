@@ -0,0 +1,349 @@
+use common::{all_errors, first_error, OrExpected, ParseError, Result};
+use std::{
+    fmt::{self, Display},
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+mod point;
+
+pub use point::{Direction, Point, NEIGHBOURS_8};
+
+/// A rectangular map stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line, converting every char with `cell`. Empty lines are skipped
+    /// and every row must have the same width.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Self> {
+        first_error(|errors| Self::read(input, cell, errors))
+    }
+
+    /// Every tile that `cell` rejects and every row not as wide as the first one, where
+    /// [`Grid::parse`] stops at the first.
+    pub fn check(input: &str, cell: impl FnMut(char) -> Option<T>) -> Vec<ParseError> {
+        all_errors(|errors| Self::read(input, cell, errors))
+    }
+
+    /// Like [`Grid::parse`], but every problem goes to `errors`, for days that check more than the
+    /// tiles. The grid is only meaningful when no error was added to `errors`.
+    pub fn read(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+        errors: &mut Vec<ParseError>,
+    ) -> Self {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            let mut row_width = 0;
+            for (index, c) in line.char_indices() {
+                match cell(c).or_expected(input, &line[index..], "a map tile") {
+                    Ok(cell) => cells.push(cell),
+                    Err(err) => errors.push(err),
+                }
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(expected) if row_width < expected => errors.push(ParseError::at(
+                    input,
+                    &line[line.len()..],
+                    format!("{expected} tiles in the row"),
+                )),
+                Some(expected) if row_width > expected => {
+                    let (extra, _) = line.char_indices().nth(expected).unwrap_or_default();
+                    errors.push(ParseError::at(
+                        input,
+                        &line[extra..],
+                        format!("the row to end after {expected} tiles"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+        if width.is_none() {
+            errors.push(ParseError::end(input, "a map"));
+        }
+        Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.x < self.width && point.y < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.contains(point)
+            .then(|| &self.cells[point.y * self.width + point.x])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.contains(point)
+            .then(|| &mut self.cells[point.y * self.width + point.x])
+    }
+
+    /// The neighbour of `point` in `direction`, if it is still inside the grid.
+    pub fn step(&self, point: Point, direction: Direction) -> Option<Point> {
+        point.step(direction).filter(|p| self.contains(*p))
+    }
+
+    /// The up to four orthogonal neighbours inside the grid, clockwise from the top.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(point, direction))
+    }
+
+    /// The up to eight neighbours inside the grid, diagonals included, clockwise from the top.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |(dx, dy)| point.offset(dx, dy))
+            .filter(|p| self.contains(*p))
+    }
+
+    /// The cells from `start` (included) walking `(dx, dy)` until leaving the grid.
+    pub fn ray(&self, start: Point, (dx, dy): (isize, isize)) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(Some(start), move |p| p.offset(dx, dy)).map_while(|p| self.get(p))
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first point, in reading order, whose cell matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(point, _)| point)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Draws the grid back to text, one line per row.
+    pub fn render(&self, mut cell: impl FnMut(&T) -> char) -> String {
+        let mut result = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            result.extend(row.iter().map(&mut cell));
+            result.push('\n');
+        }
+        result
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point).unwrap_or_else(|| {
+            panic!(
+                "{point:?} is outside of a {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is outside of a {width}x{height} grid"))
+    }
+}
+
+/// Where the only `tile` of the map is, for markers like a start or an exit. When it is missing,
+/// `expected` goes to `errors`, as does every other occurrence after the first one. The position is
+/// only meaningful when no error was added.
+pub fn find_single(input: &str, tile: char, expected: &str, errors: &mut Vec<ParseError>) -> Point {
+    let mut found = None;
+    for (y, line) in input.lines().filter(|line| !line.is_empty()).enumerate() {
+        for (x, (index, c)) in line.char_indices().enumerate() {
+            if c != tile {
+                continue;
+            }
+            match found {
+                None => found = Some(Point::new(x, y)),
+                Some(_) => errors.push(ParseError::at(
+                    input,
+                    &line[index..],
+                    format!("a single `{tile}` in the map"),
+                )),
+            }
+        }
+    }
+    found.unwrap_or_else(|| {
+        errors.push(ParseError::end(input, expected));
+        Point::new(0, 0)
+    })
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Grid::parse(s, Some)
+    }
+}
+
+impl Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|c| *c))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAP: &str = "#.#\n.S.\n#.E\n";
+
+    #[test]
+    fn parses_and_renders_back() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[Point::new(1, 1)], 'S');
+        assert_eq!(grid.position(|c| *c == 'E'), Some(Point::new(2, 2)));
+        assert_eq!(grid.to_string(), MAP);
+    }
+
+    #[test]
+    fn checks_every_row_and_tile() {
+        assert_eq!(
+            Grid::check("..\n.x.\n.\nx.\n", |c| (c == '.').then_some(())),
+            vec![
+                ParseError::new(2, 2, "a map tile", "`x.`"),
+                ParseError::new(2, 3, "the row to end after 2 tiles", "`.`"),
+                ParseError::new(3, 2, "2 tiles in the row", "end of line"),
+                ParseError::new(4, 1, "a map tile", "`x.`"),
+            ]
+        );
+        assert_eq!(Grid::check(MAP, Some), vec![]);
+    }
+
+    #[test]
+    fn finds_a_single_tile() {
+        let mut errors = Vec::new();
+        assert_eq!(
+            find_single(MAP, 'S', "a start", &mut errors),
+            Point::new(1, 1)
+        );
+        assert_eq!(errors, vec![]);
+        find_single("S.\n\n.S\n", 'S', "a start", &mut errors);
+        find_single(MAP, 'X', "an exit `X`", &mut errors);
+        assert_eq!(
+            errors,
+            vec![
+                ParseError::new(3, 2, "a single `S` in the map", "`S`"),
+                ParseError::new(4, 1, "an exit `X`", "end of input"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_ragged_rows_and_invalid_cells() {
+        assert_eq!(
+            "##\n#\n".parse::<Grid<char>>(),
+            Err(ParseError::new(2, 2, "2 tiles in the row", "end of line"))
+        );
+        assert_eq!(
+            "##\n###\n##\n".parse::<Grid<char>>(),
+            Err(ParseError::new(2, 3, "the row to end after 2 tiles", "`#`"))
+        );
+        assert_eq!(
+            Grid::parse("..\n.x\n", |c| (c == '.').then_some(())),
+            Err(ParseError::new(2, 2, "a map tile", "`x`"))
+        );
+        assert_eq!(
+            "\n".parse::<Grid<char>>(),
+            Err(ParseError::new(2, 1, "a map", "end of input"))
+        );
+    }
+
+    #[test]
+    fn out_of_bounds_access_is_none() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.step(Point::new(0, 0), Direction::Up), None);
+        assert_eq!(grid.step(Point::new(2, 1), Direction::Right), None);
+        assert_eq!(
+            grid.step(Point::new(0, 0), Direction::Down),
+            Some(Point::new(0, 1))
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(grid.neighbours4(Point::new(1, 1)).count(), 4);
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+        assert_eq!(
+            grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>(),
+            vec![Point::new(1, 0), Point::new(0, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(2, 2)).count(), 3);
+    }
+
+    #[test]
+    fn ray_stops_at_the_border() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(
+            grid.ray(Point::new(0, 0), (1, 1)).collect::<String>(),
+            "#SE"
+        );
+        assert_eq!(grid.ray(Point::new(2, 0), (1, 0)).collect::<String>(), "#");
+    }
+
+    #[test]
+    fn directions_turn_around() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_left(), direction);
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(Direction::from_arrow(direction.to_arrow()), Some(direction));
+        }
+    }
+}
@@ -0,0 +1,249 @@
+//! Shortest paths over any state type. Callers only describe a state, its neighbours and what a
+//! step costs.
+
+use common::List;
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+/// Breadth-first search where every step costs one. Returns the first goal reached and how many
+/// steps it took.
+pub fn bfs<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, u64)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        if is_goal(&state) {
+            return Some((state, steps));
+        }
+        for next in neighbours(&state) {
+            if visited.insert(next.clone()) {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Breadth-first flood from `start`: the number of steps to every reachable state.
+pub fn distances<S, I>(start: S, mut neighbours: impl FnMut(&S) -> I) -> HashMap<S, u64>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, steps)) = queue.pop_front() {
+        for next in neighbours(&state) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Cheapest path to a goal when steps have different costs. `neighbours` returns every next
+/// state with the cost of the step to it.
+pub fn dijkstra<S, I>(
+    start: S,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, u64)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/// Like [`dijkstra`], exploring first the states that `heuristic` estimates closer to a goal.
+/// The heuristic must never overestimate the remaining cost.
+pub fn astar<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<(S, u64)>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([(Reverse(heuristic(&start)), 0)]);
+    let mut states = vec![(start, 0)];
+    while let Some((_, index)) = queue.pop() {
+        let (state, cost) = states[index].clone();
+        if costs[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            return Some((state, cost));
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_some_and(|known| *known <= next_cost) {
+                continue;
+            }
+            costs.insert(next.clone(), next_cost);
+            queue.push((Reverse(next_cost + heuristic(&next)), states.len()));
+            states.push((next, next_cost));
+        }
+    }
+    None
+}
+
+/// Every cheapest path from a start to the goals, kept as the optimal predecessors of each state.
+#[derive(Debug, Clone)]
+pub struct Paths<S> {
+    pub cost: u64,
+    pub goals: Vec<S>,
+    predecessors: HashMap<S, Vec<S>>,
+}
+
+impl<S: Clone + Eq + Hash> Paths<S> {
+    /// States lying on at least one cheapest path, start and goals included.
+    pub fn states(&self) -> HashSet<S> {
+        let mut states: HashSet<S> = self.goals.iter().cloned().collect();
+        let mut to_visit = self.goals.clone();
+        while let Some(state) = to_visit.pop() {
+            for previous in self.predecessors.get(&state).into_iter().flatten() {
+                if states.insert(previous.clone()) {
+                    to_visit.push(previous.clone());
+                }
+            }
+        }
+        states
+    }
+
+    /// Every cheapest path, from the start at the head to a goal. There can be exponentially many,
+    /// but they are built back from the goals, so paths share the nodes of their common end.
+    pub fn paths(&self) -> Vec<List<S>> {
+        let mut paths = Vec::new();
+        let mut partial: Vec<List<S>> = self
+            .goals
+            .iter()
+            .map(|goal| List::new().prepend(goal.clone()))
+            .collect();
+        while let Some(path) = partial.pop() {
+            let first = path.head().expect("Paths are never empty");
+            match self.predecessors.get(first) {
+                Some(previous) => partial.extend(
+                    previous
+                        .iter()
+                        .map(|previous| path.prepend(previous.clone())),
+                ),
+                None => paths.push(path),
+            }
+        }
+        paths
+    }
+}
+
+/// Dijkstra that keeps going until every cheapest path to a goal is known.
+pub fn dijkstra_all<S, I>(
+    start: S,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Paths<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut predecessors: HashMap<S, Vec<S>> = HashMap::new();
+    let mut queue = BinaryHeap::from([(Reverse(0), 0)]);
+    let mut states = vec![(start, 0)];
+    let mut goals = Vec::new();
+    let mut best = None;
+    while let Some((Reverse(cost), index)) = queue.pop() {
+        if best.is_some_and(|best| cost > best) {
+            break;
+        }
+        let state = states[index].0.clone();
+        if costs[&state] < cost {
+            continue;
+        }
+        if is_goal(&state) {
+            best = Some(cost);
+            goals.push(state);
+            continue;
+        }
+        for (next, step) in neighbours(&state) {
+            let next_cost = cost + step;
+            match costs.get(&next) {
+                Some(known) if *known < next_cost => continue,
+                Some(known) if *known == next_cost => {
+                    predecessors.entry(next).or_default().push(state.clone());
+                    continue;
+                }
+                _ => {}
+            }
+            costs.insert(next.clone(), next_cost);
+            predecessors.insert(next.clone(), vec![state.clone()]);
+            queue.push((Reverse(next_cost), states.len()));
+            states.push((next, next_cost));
+        }
+    }
+    Some(Paths {
+        cost: best?,
+        goals,
+        predecessors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A diamond `0 -> 1 | 2 -> 3`, both halves costing the same, then a longer way `0 -> 4 -> 3`.
+    fn graph(state: &u8) -> Vec<(u8, u64)> {
+        match state {
+            0 => vec![(1, 1), (2, 2), (4, 1)],
+            1 => vec![(3, 2)],
+            2 => vec![(3, 1)],
+            4 => vec![(3, 5)],
+            _ => vec![],
+        }
+    }
+
+    fn unweighted(state: &u8) -> Vec<u8> {
+        graph(state).into_iter().map(|(next, _)| next).collect()
+    }
+
+    #[test]
+    fn breadth_first_counts_steps() {
+        assert_eq!(bfs(0, unweighted, |state| *state == 3), Some((3, 2)));
+        assert_eq!(bfs(3, unweighted, |state| *state == 0), None);
+        let distances = distances(0, unweighted);
+        assert_eq!(distances.len(), 5);
+        assert_eq!(distances[&3], 2);
+    }
+
+    #[test]
+    fn weighted_searches_find_the_cheapest_cost() {
+        assert_eq!(dijkstra(0, graph, |state| *state == 3), Some((3, 3)));
+        assert_eq!(astar(0, graph, |_| 0, |state| *state == 3), Some((3, 3)));
+        assert_eq!(dijkstra(3, graph, |state| *state == 0), None);
+    }
+
+    #[test]
+    fn keeps_every_cheapest_path() {
+        let paths = dijkstra_all(0, graph, |state| *state == 3).unwrap();
+        assert_eq!(paths.cost, 3);
+        assert_eq!(paths.states(), HashSet::from([0, 1, 2, 3]));
+        let mut all: Vec<Vec<u8>> = paths.paths().iter().map(List::to_vec).collect();
+        all.sort();
+        assert_eq!(all, vec![vec![0, 1, 3], vec![0, 2, 3]]);
+    }
+}